@@ -0,0 +1,35 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use smallnum::SmallUnsigned;
+
+const LEN: usize = 4_096;
+
+// Baseline: check and narrow per element, with an early exit on the first overflow
+fn narrow_naive(src: &[usize], dst: &mut [u16]) -> Result<(), usize> {
+    for (i, num) in src.iter().enumerate() {
+        if *num > (u16::MAX as usize) {
+            return Err(i);
+        }
+        dst[i] = u16::checked_from(*num);
+    }
+    Ok(())
+}
+
+fn bench_narrow(c: &mut Criterion) {
+    let src: Vec<usize> = (0..LEN).map(|i| i % (u16::MAX as usize)).collect();
+    let mut dst = vec![0_u16; LEN];
+
+    let mut group = c.benchmark_group("narrow_u16");
+
+    group.bench_function("naive", |b| {
+        b.iter(|| narrow_naive(black_box(&src), black_box(&mut dst)))
+    });
+
+    group.bench_function("narrow_checked", |b| {
+        b.iter(|| u16::narrow_checked(black_box(&src), black_box(&mut dst)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_narrow);
+criterion_main!(benches);
@@ -0,0 +1,55 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use smallnum::{SmallSigned, SmallUnsigned};
+
+const LEN: usize = 4_096;
+
+// Baseline: per-element indexing, leaves bounds check elimination up to the optimizer
+fn widen_naive(src: &[u16], dst: &mut [usize]) {
+    for i in 0..src.len() {
+        dst[i] = src[i].usize();
+    }
+}
+
+// Signed counterpart of `widen_naive`
+fn widen_signed_naive(src: &[i16], dst: &mut [isize]) {
+    for i in 0..src.len() {
+        dst[i] = src[i].isize();
+    }
+}
+
+fn bench_widen(c: &mut Criterion) {
+    let src: Vec<u16> = (0..LEN).map(|i| (i % (u16::MAX as usize)) as u16).collect();
+    let mut dst = vec![0_usize; LEN];
+
+    let mut group = c.benchmark_group("widen_u16");
+
+    group.bench_function("naive", |b| {
+        b.iter(|| widen_naive(black_box(&src), black_box(&mut dst)))
+    });
+
+    group.bench_function("widen_slice_into", |b| {
+        b.iter(|| u16::widen_slice_into(black_box(&src), black_box(&mut dst)))
+    });
+
+    group.finish();
+}
+
+fn bench_widen_signed(c: &mut Criterion) {
+    let src: Vec<i16> = (0..LEN).map(|i| ((i % 512) as i16) - 256).collect();
+    let mut dst = vec![0_isize; LEN];
+
+    let mut group = c.benchmark_group("widen_i16");
+
+    group.bench_function("naive", |b| {
+        b.iter(|| widen_signed_naive(black_box(&src), black_box(&mut dst)))
+    });
+
+    group.bench_function("widen_slice_into", |b| {
+        b.iter(|| i16::widen_slice_into(black_box(&src), black_box(&mut dst)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_widen, bench_widen_signed);
+criterion_main!(benches);
@@ -0,0 +1,126 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Adjacency matrix for a small, dense, directed graph: one bit per (from, to) pair.
+// The matrix itself is already compact, but node IDs show up everywhere else (edge lists, queues, labels),
+// and those are bounded by the node count so they're size-optimized.
+
+const MAX_NODES: usize = 128;
+const WORD_BITS: usize = u64::BITS as usize;
+const ROW_WORDS: usize = MAX_NODES / WORD_BITS;
+
+type NodeId = small_unsigned!(MAX_NODES);
+
+struct AdjMatrix {
+    rows: [[u64; ROW_WORDS]; MAX_NODES],
+}
+
+impl AdjMatrix {
+    fn new() -> Self {
+        AdjMatrix {
+            rows: [[0; ROW_WORDS]; MAX_NODES],
+        }
+    }
+
+    fn set_edge(&mut self, from: NodeId, to: NodeId) {
+        let to = to.usize();
+        self.rows[from.as_index()][to / WORD_BITS] |= 1 << (to % WORD_BITS);
+    }
+
+    fn clear_edge(&mut self, from: NodeId, to: NodeId) {
+        let to = to.usize();
+        self.rows[from.as_index()][to / WORD_BITS] &= !(1 << (to % WORD_BITS));
+    }
+
+    fn has_edge(&self, from: NodeId, to: NodeId) -> bool {
+        let to = to.usize();
+        ((self.rows[from.as_index()][to / WORD_BITS] >> (to % WORD_BITS)) & 1) == 1
+    }
+
+    fn out_degree(&self, from: NodeId) -> usize {
+        self.rows[from.as_index()]
+            .iter()
+            .map(|w| w.count_ones() as usize)
+            .sum()
+    }
+
+    fn neighbors(&self, from: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        (0..MAX_NODES)
+            .map(NodeId::checked_from)
+            .filter(move |to| self.has_edge(from, *to))
+    }
+
+    // Hop count from `src` to every node, `None` if unreachable
+    fn bfs(&self, src: NodeId) -> [Option<NodeId>; MAX_NODES] {
+        let mut dist = [None; MAX_NODES];
+        let mut queue = [0 as NodeId; MAX_NODES];
+        let (mut head, mut tail) = (0, 1);
+
+        queue[0] = src;
+        dist[src.as_index()] = Some(0);
+
+        while head < tail {
+            let node = queue[head];
+            head += 1;
+
+            let next = NodeId::checked_from(dist[node.as_index()].unwrap().usize() + 1);
+            for n in self.neighbors(node) {
+                if dist[n.as_index()].is_none() {
+                    dist[n.as_index()] = Some(next);
+                    queue[tail] = n;
+                    tail += 1;
+                }
+            }
+        }
+
+        dist
+    }
+}
+
+fn main() {
+    let mut g = AdjMatrix::new();
+
+    // Directed ring, plus a chord
+    for i in 0..MAX_NODES {
+        g.set_edge(
+            NodeId::checked_from(i),
+            NodeId::checked_from((i + 1) % MAX_NODES),
+        );
+    }
+    g.set_edge(0, 64);
+
+    assert!(g.has_edge(0, 1));
+    assert!(!g.has_edge(1, 0));
+    assert!(g.has_edge(127, 0));
+    assert_eq!(g.out_degree(0), 2);
+    assert_eq!(g.neighbors(0).collect::<Vec<_>>(), vec![1, 64]);
+
+    // Chord halves the distance to the far side
+    let dist = g.bfs(0);
+    assert_eq!(dist[64], Some(1));
+    assert_eq!(dist[127], Some(64));
+
+    g.clear_edge(0, 64);
+    assert_eq!(g.out_degree(0), 1);
+    assert_eq!(g.bfs(0)[64], Some(64));
+
+    // Node ID storage: 1 byte per queue entry, 2 per distance (vs. 8 and 16 with `usize`)
+    let unoptimized_queue = [0_usize; MAX_NODES];
+    let unoptimized_dist = [None::<usize>; MAX_NODES];
+    assert_eq!(size_of_val(&g.bfs(0)), 2 * MAX_NODES);
+
+    println!(
+        "matrix: {} bytes, BFS state: {} bytes (vs. {} bytes with usize IDs)",
+        size_of_val(&g.rows),
+        size_of_val(&[0 as NodeId; MAX_NODES]) + size_of_val(&dist),
+        size_of_val(&unoptimized_queue) + size_of_val(&unoptimized_dist),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn adj_matrix() {
+        super::main();
+    }
+}
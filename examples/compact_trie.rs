@@ -0,0 +1,171 @@
+use core::mem::size_of;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Trie over a bounded alphabet (lowercase ASCII) with a bounded node count.
+// Child pointers index the node arena, so they shrink with capacity. `0` is the "no child" sentinel,
+// since the root (node `0`) is never a child. Alphabet positions shrink too.
+
+const ALPHABET: usize = 26;
+const MAX_NODES: usize = 4_096;
+
+type NodeIdx = small_unsigned!(MAX_NODES - 1);
+type Letter = small_unsigned!(ALPHABET - 1);
+
+#[derive(Copy, Clone)]
+pub struct Node {
+    children: [NodeIdx; ALPHABET],
+    terminal: bool,
+}
+
+// Same node, unoptimized
+#[allow(dead_code)]
+pub struct UsizeNode {
+    children: [usize; ALPHABET],
+    terminal: bool,
+}
+
+struct Trie {
+    nodes: Vec<Node>,
+}
+
+// Alphabet position of `c` (e.g. `'c'` -> `2`), if it's lowercase ASCII
+fn letter(c: char) -> Option<Letter> {
+    if !c.is_ascii_lowercase() {
+        return None;
+    }
+
+    // Radix 36 digits: '0'..='9' are 0..=9, 'a'..='z' are 10..=35
+    u8::from_digit_char(c, 36).map(|d| Letter::checked_from(d.usize() - 10))
+}
+
+impl Trie {
+    fn new() -> Self {
+        Trie {
+            nodes: vec![Node {
+                children: [0; ALPHABET],
+                terminal: false,
+            }],
+        }
+    }
+
+    // `Vec` only indexes by `usize`, so go through its slice
+    fn node(&self, idx: NodeIdx) -> &Node {
+        &self.nodes.as_slice()[idx.as_index()]
+    }
+
+    fn node_mut(&mut self, idx: NodeIdx) -> &mut Node {
+        &mut self.nodes.as_mut_slice()[idx.as_index()]
+    }
+
+    // Returns `false` if the word has an out-of-alphabet char or the arena is full
+    fn insert(&mut self, word: &str) -> bool {
+        let mut curr = NodeIdx::checked_from(0);
+
+        for c in word.chars() {
+            let l = match letter(c) {
+                Some(l) => l,
+                None => return false,
+            };
+
+            let child = self.node(curr).children[l.as_index()];
+            curr = if child.is_zero() {
+                if self.nodes.len() == MAX_NODES {
+                    return false;
+                }
+
+                let new = NodeIdx::checked_from(self.nodes.len());
+                self.nodes.push(Node {
+                    children: [0; ALPHABET],
+                    terminal: false,
+                });
+                self.node_mut(curr).children[l.as_index()] = new;
+                new
+            } else {
+                child
+            };
+        }
+
+        self.node_mut(curr).terminal = true;
+        true
+    }
+
+    fn find(&self, prefix: &str) -> Option<&Node> {
+        let mut curr = NodeIdx::checked_from(0);
+
+        for c in prefix.chars() {
+            let child = self.node(curr).children[letter(c)?.as_index()];
+            if child.is_zero() {
+                return None;
+            }
+            curr = child;
+        }
+
+        Some(self.node(curr))
+    }
+
+    fn contains(&self, word: &str) -> bool {
+        self.find(word).map(|n| n.terminal).unwrap_or(false)
+    }
+
+    fn has_prefix(&self, prefix: &str) -> bool {
+        self.find(prefix).is_some()
+    }
+}
+
+fn main() {
+    let mut trie = Trie::new();
+
+    for word in ["tea", "ten", "to", "inn", "in", "i"] {
+        assert!(trie.insert(word));
+    }
+
+    // Lookups
+    assert!(trie.contains("tea"));
+    assert!(trie.contains("in"));
+    assert!(trie.contains("i"));
+    assert!(!trie.contains("te"));
+    assert!(!trie.contains("tens"));
+    assert!(trie.has_prefix("te"));
+    assert!(!trie.has_prefix("x"));
+
+    // Out-of-alphabet input is rejected
+    assert!(!trie.insert("Tea"));
+    assert!(!trie.contains("t3a"));
+
+    // Shared prefixes share nodes: root + t,e,a,n,o + i,n,n
+    assert_eq!(trie.nodes.len(), 9);
+
+    // Fill the arena, then inserts needing new nodes fail
+    let mut i = 0;
+    while trie.nodes.len() < MAX_NODES {
+        let word: String = format!("{:x}", 0x10000 + i)
+            .chars()
+            .map(|c| (b'a' + c.to_digit(16).unwrap() as u8) as char)
+            .collect();
+        assert!(trie.insert(&word));
+        i += 1;
+    }
+    assert!(!trie.insert("zzzzzz"));
+    assert!(trie.insert("tea"));
+
+    // Footprint: 2 bytes per child pointer, 1 byte per letter, node padded to 2-byte alignment
+    assert_eq!(size_of::<NodeIdx>(), 2);
+    assert_eq!(size_of::<Letter>(), 1);
+    assert_eq!(size_of::<Node>(), (ALPHABET * 2) + 2);
+
+    println!(
+        "node: {} bytes (vs. {} bytes with usize children), arena: {} bytes (vs. {} bytes)",
+        size_of::<Node>(),
+        size_of::<UsizeNode>(),
+        size_of::<Node>() * MAX_NODES,
+        size_of::<UsizeNode>() * MAX_NODES,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn compact_trie() {
+        super::main();
+    }
+}
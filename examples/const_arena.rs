@@ -1,6 +1,5 @@
-// We use nightly Rust ONLY to print our arena to the console for illustrative purposes.
-// In a real program, this example would omit the print and be compatible with stable 1.51+
-#![feature(type_name_of_val)]
+// We use `type_name_of_val` (stable 1.76+) ONLY to print our arena to the console for illustrative purposes.
+// In a real program, this example would omit the print and build on the crate's MSRV (`rust-version` in Cargo.toml)
 
 use smallnum::{small_unsigned, SmallUnsigned};
 
@@ -66,12 +65,16 @@ fn main() {
     assert_eq!(arena.free_list.len(), MAX_CAPACITY);
     assert_eq!(arena.len(), MAX_CAPACITY);
 
-    println!(
-        "arena.storage<{}>",
-        std::any::type_name_of_val(&arena.storage)
-    );
-    println!(
-        "arena.free_list<{}>",
-        std::any::type_name_of_val(&arena.free_list)
-    );
+    // Illustrative only, above MSRV (see top of file)
+    #[allow(clippy::incompatible_msrv)]
+    {
+        println!(
+            "arena.storage<{}>",
+            std::any::type_name_of_val(&arena.storage)
+        );
+        println!(
+            "arena.free_list<{}>",
+            std::any::type_name_of_val(&arena.free_list)
+        );
+    }
 }
@@ -0,0 +1,108 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Sparse matrix in CSR (compressed sparse row) form.
+// Row pointers are offsets into the non-zero arrays, bounded by the non-zero count.
+// Column indexes are bounded by the column count. Both arrays shrink independently.
+
+const N: usize = 200;
+
+// Tridiagonal: 3 per row, minus the 2 that fall off the corners
+const NNZ: usize = (3 * N) - 2;
+
+type RowPtr = small_unsigned!(NNZ);
+type ColIdx = small_unsigned!(N);
+
+struct Csr {
+    row_ptr: Vec<RowPtr>,
+    col_idx: Vec<ColIdx>,
+    vals: Vec<f64>,
+}
+
+impl Csr {
+    // Build from row-major `(row, col, val)` triplets
+    fn from_triplets(triplets: &[(usize, usize, f64)]) -> Self {
+        let mut row_ptr = vec![RowPtr::checked_from(0); N + 1];
+        let mut col_idx = Vec::with_capacity(triplets.len());
+        let mut vals = Vec::with_capacity(triplets.len());
+
+        for &(row, col, val) in triplets {
+            row_ptr[row + 1] = RowPtr::checked_from(row_ptr[row + 1].usize() + 1);
+            col_idx.push(ColIdx::checked_from(col));
+            vals.push(val);
+        }
+
+        // Per-row counts -> offsets
+        for row in 0..N {
+            row_ptr[row + 1] =
+                RowPtr::checked_from(row_ptr[row].usize() + row_ptr[row + 1].usize());
+        }
+
+        Csr {
+            row_ptr,
+            col_idx,
+            vals,
+        }
+    }
+
+    fn mul_vec(&self, x: &[f64]) -> Vec<f64> {
+        (0..N)
+            .map(|row| {
+                let (start, end) = (self.row_ptr[row].usize(), self.row_ptr[row + 1].usize());
+                (start..end)
+                    .map(|i| self.vals[i] * x[self.col_idx[i].usize()])
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+fn main() {
+    // 1D Laplacian: 2 on the diagonal, -1 on either side
+    let mut triplets = Vec::new();
+    for row in 0..N {
+        if row > 0 {
+            triplets.push((row, row - 1, -1.0));
+        }
+        triplets.push((row, row, 2.0));
+        if row < (N - 1) {
+            triplets.push((row, row + 1, -1.0));
+        }
+    }
+    assert_eq!(triplets.len(), NNZ);
+
+    let a = Csr::from_triplets(&triplets);
+    assert_eq!(a.row_ptr[N].usize(), NNZ);
+
+    // Constant vector: interior rows cancel, boundary rows keep one neighbor's worth
+    let y = a.mul_vec(&[1.0; N]);
+    assert_eq!(y[0], 1.0);
+    assert_eq!(y[N - 1], 1.0);
+    assert!(y[1..(N - 1)].iter().all(|v| *v == 0.0));
+
+    // Linear vector: interior rows cancel, boundaries pick up the missing neighbor
+    let x: Vec<f64> = (0..N).map(|i| i as f64).collect();
+    let y = a.mul_vec(&x);
+    assert_eq!(y[0], -1.0);
+    assert_eq!(y[N - 1], N as f64);
+    assert!(y[1..(N - 1)].iter().all(|v| *v == 0.0));
+
+    // Index storage: 2 bytes per row pointer, 1 byte per column index
+    assert_eq!(size_of_val(&a.row_ptr[..]), 2 * (N + 1));
+    assert_eq!(size_of_val(&a.col_idx[..]), NNZ);
+
+    let unoptimized = (N + 1 + NNZ) * core::mem::size_of::<usize>();
+    println!(
+        "index storage: {} bytes (vs. {} bytes with usize)",
+        size_of_val(&a.row_ptr[..]) + size_of_val(&a.col_idx[..]),
+        unoptimized
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn csr() {
+        super::main();
+    }
+}
@@ -0,0 +1,80 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Histogram of byte values, where bin counts saturate at a cap and anything past it is tallied separately.
+// Capped bins fit in `u8`, so a large bin array costs one byte per bin.
+
+const NUM_BINS: usize = 4_096;
+const MAX_COUNT: usize = 255;
+
+type Count = small_unsigned!(MAX_COUNT);
+
+struct Histogram {
+    bins: [Count; NUM_BINS],
+
+    // Increments dropped by saturated bins
+    overflow: usize,
+}
+
+impl Histogram {
+    fn new() -> Self {
+        Histogram {
+            bins: [0; NUM_BINS],
+            overflow: 0,
+        }
+    }
+
+    // Saturating increment, past-cap hits are counted as overflow
+    fn record(&mut self, sample: usize) {
+        let bin = &mut self.bins[sample % NUM_BINS];
+        if bin.usize() < MAX_COUNT {
+            *bin = Count::checked_from(bin.usize() + 1);
+        } else {
+            self.overflow += 1;
+        }
+    }
+
+    fn count(&self, bin: usize) -> usize {
+        self.bins[bin].usize()
+    }
+}
+
+fn main() {
+    let mut hist = Histogram::new();
+
+    // Skewed samples: bin 0 is hot, the rest see a few hits each
+    for _ in 0..1_000 {
+        hist.record(0);
+    }
+    for sample in 1..(NUM_BINS * 3) {
+        hist.record(sample);
+    }
+
+    assert_eq!(hist.count(0), MAX_COUNT);
+    assert_eq!(hist.overflow, 1_000 + 2 - MAX_COUNT);
+    assert_eq!(hist.count(1), 3);
+    assert_eq!(hist.count(NUM_BINS - 1), 3);
+
+    // Total hits are preserved across bins and overflow
+    let total: usize = hist.bins.iter().map(|b| b.usize()).sum::<usize>() + hist.overflow;
+    assert_eq!(total, 1_000 + (NUM_BINS * 3) - 1);
+
+    // 1 byte per bin (vs. 8 with `usize` on a 64-bit host)
+    let unoptimized = [0_usize; NUM_BINS];
+    assert_eq!(size_of_val(&hist.bins), NUM_BINS);
+
+    println!(
+        "bins: {} bytes (vs. {} bytes with usize bins), overflow: {}",
+        size_of_val(&hist.bins),
+        size_of_val(&unoptimized),
+        hist.overflow
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn histogram() {
+        super::main();
+    }
+}
@@ -0,0 +1,146 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Canonical Huffman coding (as in DEFLATE) only stores a code length per symbol, codes are rebuilt from lengths.
+// Lengths are bounded by the max code length, so the length table shrinks to a byte per symbol.
+
+const NUM_SYMBOLS: usize = 256;
+const MAX_CODE_LEN: usize = 15;
+
+type CodeLen = small_unsigned!(MAX_CODE_LEN);
+
+// Huffman code length per symbol, `0` for symbols that never occur
+fn code_lengths(freqs: &[usize; NUM_SYMBOLS]) -> [CodeLen; NUM_SYMBOLS] {
+    // (weight, parent) per node, leaves first
+    let mut nodes: Vec<(usize, Option<usize>)> = freqs
+        .iter()
+        .filter(|f| **f > 0)
+        .map(|f| (*f, None))
+        .collect();
+    let num_leaves = nodes.len();
+
+    // Merge the two lightest orphans until one root remains
+    loop {
+        let mut orphans: Vec<usize> = (0..nodes.len()).filter(|i| nodes[*i].1.is_none()).collect();
+        if orphans.len() < 2 {
+            break;
+        }
+
+        orphans.sort_by_key(|i| nodes[*i].0);
+        let (a, b) = (orphans[0], orphans[1]);
+        let parent = nodes.len();
+
+        nodes.push((nodes[a].0 + nodes[b].0, None));
+        nodes[a].1 = Some(parent);
+        nodes[b].1 = Some(parent);
+    }
+
+    let mut lens = [0 as CodeLen; NUM_SYMBOLS];
+    let used = (0..NUM_SYMBOLS).filter(|sym| freqs[*sym] > 0);
+    for (leaf, sym) in (0..num_leaves).zip(used) {
+        let mut depth = 0;
+        let mut node = leaf;
+        while let Some(parent) = nodes[node].1 {
+            depth += 1;
+            node = parent;
+        }
+
+        // Lone symbol still needs a 1-bit code
+        lens[sym] = CodeLen::checked_from(depth.max(1));
+    }
+
+    lens
+}
+
+// Canonical codes from lengths (RFC 1951, section 3.2.2)
+fn canonical_codes(lens: &[CodeLen]) -> Vec<u16> {
+    let mut bl_count = [0_u16; MAX_CODE_LEN + 1];
+    for len in lens.iter().filter(|len| !len.is_zero()) {
+        bl_count[len.as_index()] += 1;
+    }
+
+    let mut next_code = [0_u16; MAX_CODE_LEN + 1];
+    let mut code = 0;
+    for bits in 1..=MAX_CODE_LEN {
+        code = (code + bl_count[bits - 1]) << 1;
+        next_code[bits] = code;
+    }
+
+    lens.iter()
+        .map(|len| {
+            if len.is_zero() {
+                return 0;
+            }
+            let code = next_code[len.as_index()];
+            next_code[len.as_index()] += 1;
+            code
+        })
+        .collect()
+}
+
+fn main() {
+    // RFC 1951 example: lengths (3, 3, 3, 3, 3, 2, 4, 4) for symbols A-H
+    let lens: [CodeLen; 8] = [3, 3, 3, 3, 3, 2, 4, 4];
+    assert_eq!(
+        canonical_codes(&lens),
+        vec![0b010, 0b011, 0b100, 0b101, 0b110, 0b00, 0b1110, 0b1111]
+    );
+
+    // Lengths from byte frequencies of some text
+    let text = b"this is an example of a huffman tree, built from byte frequencies of this text";
+    let mut freqs = [0; NUM_SYMBOLS];
+    for b in text.iter() {
+        freqs[*b as usize] += 1;
+    }
+
+    let lens = code_lengths(&freqs);
+    let codes = canonical_codes(&lens);
+
+    // Only occurring symbols get codes, more frequent ones never get longer codes
+    assert!((0..NUM_SYMBOLS).all(|sym| (freqs[sym] == 0) == lens[sym].is_zero()));
+    assert!(lens[b' ' as usize] <= lens[b'x' as usize]);
+    assert!(lens.iter().all(|len| len.usize() <= MAX_CODE_LEN));
+
+    // Complete code: Kraft sum is exactly 1
+    let kraft: usize = lens
+        .iter()
+        .filter(|len| !len.is_zero())
+        .map(|len| 1 << (MAX_CODE_LEN - len.usize()))
+        .sum();
+    assert_eq!(kraft, 1 << MAX_CODE_LEN);
+
+    // Prefix-free: no code is a prefix of another
+    let used: Vec<usize> = (0..NUM_SYMBOLS).filter(|sym| freqs[*sym] > 0).collect();
+    for &a in &used {
+        for &b in &used {
+            if (a != b) && (lens[a] <= lens[b]) {
+                let shift = lens[b].usize() - lens[a].usize();
+                assert_ne!(codes[b] >> shift, codes[a]);
+            }
+        }
+    }
+
+    // Compression vs. fixed 8-bit codes
+    let bits: usize = text.iter().map(|b| lens[*b as usize].usize()).sum();
+    assert!(bits < (text.len() * 8));
+
+    // Length table: 1 byte per symbol (vs. 8 with `usize`)
+    let unoptimized = [0_usize; NUM_SYMBOLS];
+    assert_eq!(size_of_val(&lens), NUM_SYMBOLS);
+
+    println!(
+        "{} bits (vs. {} uncompressed), length table: {} bytes (vs. {} bytes with usize)",
+        bits,
+        text.len() * 8,
+        size_of_val(&lens),
+        size_of_val(&unoptimized)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn huffman_lengths() {
+        super::main();
+    }
+}
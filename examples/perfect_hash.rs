@@ -0,0 +1,100 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned, SmallUnsignedLabel};
+
+// A perfect hash maps each key of a static set to a distinct slot, so lookups never probe.
+// Slots store an index into the key set, which is bounded by the set's size and can be size-optimized.
+
+const KEYS: [&str; 12] = [
+    "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for", "if",
+    "impl",
+];
+
+const NUM_KEYS: usize = KEYS.len();
+const NUM_SLOTS: usize = 32;
+
+/// Recommended slot index type for a set of `num_keys` keys.
+/// Indexes span `0..num_keys`, and `num_keys` itself is reserved as the "empty slot" sentinel.
+const fn slot_index_type(num_keys: usize) -> SmallUnsignedLabel {
+    SmallUnsignedLabel::new(num_keys)
+}
+
+// Same bound as `slot_index_type`, but at the type level
+type SlotIdx = small_unsigned!(NUM_KEYS);
+
+const EMPTY: usize = NUM_KEYS;
+
+pub struct PerfectHash {
+    seed: u64,
+    slots: [SlotIdx; NUM_SLOTS],
+}
+
+// FNV-1a, seeded
+fn hash(key: &str, seed: u64) -> usize {
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ seed;
+    for b in key.bytes() {
+        h ^= b as u64;
+        h = h.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    (h % (NUM_SLOTS as u64)) as usize
+}
+
+impl PerfectHash {
+    // Search for a seed under which no two keys collide
+    pub fn new(keys: &[&str; NUM_KEYS]) -> Self {
+        'seed: for seed in 0.. {
+            let mut slots = [SlotIdx::checked_from(EMPTY); NUM_SLOTS];
+            for (i, key) in keys.iter().enumerate() {
+                let slot = &mut slots[hash(key, seed)];
+                if slot.usize() != EMPTY {
+                    continue 'seed;
+                }
+                *slot = SlotIdx::checked_from(i);
+            }
+            return PerfectHash { seed, slots };
+        }
+        unreachable!()
+    }
+
+    pub fn get(&self, key: &str) -> Option<usize> {
+        let idx = self.slots[hash(key, self.seed)].usize();
+        if (idx != EMPTY) && (KEYS[idx] == key) {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+}
+
+fn main() {
+    let ph = PerfectHash::new(&KEYS);
+
+    // Every key maps to its own index
+    for (i, key) in KEYS.iter().enumerate() {
+        assert_eq!(ph.get(key), Some(i));
+    }
+
+    // Non-members are rejected
+    for key in ["while", "loop", "match", ""] {
+        assert_eq!(ph.get(key), None);
+    }
+
+    // Recommendation agrees with the selected type
+    assert_eq!(slot_index_type(NUM_KEYS), SmallUnsignedLabel::U8);
+    assert_eq!(size_of_val(&ph.slots), NUM_SLOTS);
+
+    let unoptimized = [0_usize; NUM_SLOTS];
+    println!(
+        "seed: {}, slot table: {} bytes (vs. {} bytes with usize slots)",
+        ph.seed,
+        size_of_val(&ph.slots),
+        size_of_val(&unoptimized)
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn perfect_hash() {
+        super::main();
+    }
+}
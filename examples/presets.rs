@@ -0,0 +1,124 @@
+use core::mem::size_of;
+use smallnum::{small_unsigned, SmallUnsigned, SmallUnsignedLabel};
+
+// Config-driven sizing: capacity comes from a preset picked at runtime (e.g. from a config file), so the macros
+// can't select the type. Instead, map each preset to a label, and dispatch to storage of the labeled width once.
+// Everything past the dispatch is monomorphized, so the hot path doesn't branch on the preset.
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Preset {
+    Tiny,
+    Small,
+    Large,
+}
+
+impl Preset {
+    const ALL: [Preset; 3] = [Preset::Tiny, Preset::Small, Preset::Large];
+
+    const fn max_entries(self) -> usize {
+        match self {
+            Preset::Tiny => 200,
+            Preset::Small => 50_000,
+            Preset::Large => 4_000_000,
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        Preset::ALL
+            .iter()
+            .copied()
+            .find(|p| format!("{:?}", p).eq_ignore_ascii_case(s))
+    }
+}
+
+// Const, so a preset known at compile time can still be checked against the macro's selection
+const fn label_for_preset(p: Preset) -> SmallUnsignedLabel {
+    SmallUnsignedLabel::new(p.max_entries())
+}
+
+// Entry IDs, stored at the width the preset's label selects
+enum IdStore {
+    U8(Vec<u8>),
+    U16(Vec<u16>),
+    U32(Vec<u32>),
+}
+
+impl IdStore {
+    fn for_preset(p: Preset) -> Self {
+        match label_for_preset(p) {
+            SmallUnsignedLabel::U8 => IdStore::U8(Vec::new()),
+            SmallUnsignedLabel::U16 => IdStore::U16(Vec::new()),
+            SmallUnsignedLabel::U32 => IdStore::U32(Vec::new()),
+            label => unreachable!("no preset needs {}", label.type_name()),
+        }
+    }
+
+    // Monomorphized once per width
+    fn fill<T: SmallUnsigned>(ids: &mut Vec<T>, count: usize) {
+        ids.extend((0..count).map(T::checked_from));
+    }
+
+    fn fill_all(&mut self, count: usize) {
+        match self {
+            IdStore::U8(ids) => Self::fill(ids, count),
+            IdStore::U16(ids) => Self::fill(ids, count),
+            IdStore::U32(ids) => Self::fill(ids, count),
+        }
+    }
+
+    fn get(&self, i: usize) -> Option<usize> {
+        match self {
+            IdStore::U8(ids) => ids.get(i).map(SmallUnsigned::usize),
+            IdStore::U16(ids) => ids.get(i).map(SmallUnsigned::usize),
+            IdStore::U32(ids) => ids.get(i).map(SmallUnsigned::usize),
+        }
+    }
+
+    fn bytes_per_id(&self) -> usize {
+        match self {
+            IdStore::U8(_) => size_of::<u8>(),
+            IdStore::U16(_) => size_of::<u16>(),
+            IdStore::U32(_) => size_of::<u32>(),
+        }
+    }
+}
+
+fn main() {
+    // Each preset maps to the expected label
+    assert_eq!(label_for_preset(Preset::Tiny), SmallUnsignedLabel::U8);
+    assert_eq!(label_for_preset(Preset::Small), SmallUnsignedLabel::U16);
+    assert_eq!(label_for_preset(Preset::Large), SmallUnsignedLabel::U32);
+
+    // Runtime and compile-time selection agree
+    const SMALL: Preset = Preset::Small;
+    assert_eq!(
+        label_for_preset(SMALL).byte_size(),
+        size_of::<small_unsigned!(SMALL.max_entries())>()
+    );
+
+    // "From config"
+    for (setting, expected_bytes) in [("tiny", 1), ("Small", 2), ("LARGE", 4)] {
+        let preset = Preset::parse(setting).expect("unknown preset");
+        let mut store = IdStore::for_preset(preset);
+        store.fill_all(preset.max_entries().min(1_000));
+
+        assert_eq!(store.bytes_per_id(), expected_bytes);
+        assert_eq!(store.get(199), Some(199));
+
+        println!(
+            "{:?}: up to {} entries, {} per ID",
+            preset,
+            preset.max_entries(),
+            label_for_preset(preset).type_name()
+        );
+    }
+    assert_eq!(Preset::parse("huge"), None);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn presets() {
+        super::main();
+    }
+}
@@ -0,0 +1,103 @@
+use core::mem::size_of;
+use smallnum::{small_unsigned, SmallUnsignedLabel};
+
+// Audit a set of capacity constants: for each bound, print the type `small_unsigned!` selects,
+// its size in bytes, and how many bytes it saves over a plain `usize`.
+// Run with `cargo run --example print_selection`, or `cargo test --examples` to only check the assertions.
+
+const MAX_CLIENTS: usize = 200;
+const MAX_NODES: usize = 50_000;
+const MAX_EDGES: usize = 100_000;
+
+#[cfg(target_pointer_width = "64")]
+const MAX_RECORDS: usize = 5_000_000_000;
+
+pub struct Selection {
+    pub name: &'static str,
+    pub max: usize,
+    pub label: SmallUnsignedLabel,
+    pub bytes: usize,
+}
+
+impl Selection {
+    pub fn savings(&self) -> usize {
+        size_of::<usize>() - self.bytes
+    }
+}
+
+// Label is computed at runtime, size is taken from the type the macro selected at compile-time.
+// The two must always agree.
+macro_rules! selection {
+    ( $max:ident ) => {
+        Selection {
+            name: stringify!($max),
+            max: $max,
+            label: SmallUnsignedLabel::new($max),
+            bytes: size_of::<small_unsigned!($max)>(),
+        }
+    };
+}
+
+fn selections() -> Vec<Selection> {
+    let mut sel = vec![
+        selection!(MAX_CLIENTS),
+        selection!(MAX_NODES),
+        selection!(MAX_EDGES),
+    ];
+
+    #[cfg(target_pointer_width = "64")]
+    sel.push(selection!(MAX_RECORDS));
+
+    sel
+}
+
+fn main() {
+    let sel = selections();
+
+    println!(
+        "{:<12} {:>14} {:>6} {:>6} {:>8}",
+        "bound", "max", "type", "bytes", "savings"
+    );
+    for s in &sel {
+        println!(
+            "{:<12} {:>14} {:>6} {:>6} {:>8}",
+            s.name,
+            s.max,
+            s.label.type_name(),
+            s.bytes,
+            s.savings()
+        );
+    }
+
+    assert_eq!(sel[0].label.type_name(), "u8");
+    assert_eq!(sel[1].label.type_name(), "u16");
+    assert_eq!(sel[2].label.type_name(), "u32");
+
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert_eq!(sel[3].label.type_name(), "u64");
+        assert_eq!(sel[3].savings(), 0);
+    }
+
+    for s in &sel {
+        assert_eq!(
+            s.bytes,
+            match s.label {
+                SmallUnsignedLabel::U8 => size_of::<u8>(),
+                SmallUnsignedLabel::U16 => size_of::<u16>(),
+                SmallUnsignedLabel::U32 => size_of::<u32>(),
+                SmallUnsignedLabel::U64 => size_of::<u64>(),
+                SmallUnsignedLabel::U128 => size_of::<u128>(),
+                SmallUnsignedLabel::USIZE => size_of::<usize>(),
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn print_selection() {
+        super::main();
+    }
+}
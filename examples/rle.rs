@@ -0,0 +1,100 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Run-length encoding with bounded run lengths, so each run's count fits a small type.
+// Runs longer than the bound are split into multiple runs of the same byte.
+
+const MAX_RUN: usize = 255;
+
+type RunLen = small_unsigned!(MAX_RUN);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Run {
+    byte: u8,
+    len: RunLen,
+}
+
+// Same run, unoptimized
+#[allow(dead_code)]
+pub struct UsizeRun {
+    byte: u8,
+    len: usize,
+}
+
+fn encode(data: &[u8]) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+
+    for &byte in data {
+        match runs.last_mut() {
+            // Extend the current run, unless it's full
+            Some(run) if (run.byte == byte) && (run.len.usize() < MAX_RUN) => {
+                run.len = RunLen::checked_from(run.len.usize() + 1);
+            }
+            _ => runs.push(Run {
+                byte,
+                len: RunLen::checked_from(1),
+            }),
+        }
+    }
+
+    runs
+}
+
+fn decode(runs: &[Run]) -> Vec<u8> {
+    let mut data = Vec::new();
+    for run in runs {
+        data.extend(core::iter::repeat(run.byte).take(run.len.usize()));
+    }
+    data
+}
+
+fn main() {
+    // Short runs
+    let data = b"aaabccdddd";
+    let runs = encode(data);
+    assert_eq!(runs.len(), 4);
+    assert_eq!(runs[0], Run { byte: b'a', len: 3 });
+    assert_eq!(runs[3], Run { byte: b'd', len: 4 });
+    assert_eq!(decode(&runs), data);
+
+    // A run past the bound is split: 600 = 255 + 255 + 90
+    let mut long = vec![0_u8; 600];
+    long.extend_from_slice(&[1, 1]);
+    let runs = encode(&long);
+    assert_eq!(runs.len(), 4);
+    assert!(runs[..2]
+        .iter()
+        .all(|r| (r.byte == 0) && r.len.is_type_max()));
+    assert_eq!(runs[2], Run { byte: 0, len: 90 });
+    assert_eq!(runs[3], Run { byte: 1, len: 2 });
+    assert_eq!(decode(&runs), long);
+
+    // Empty input
+    assert!(encode(&[]).is_empty());
+    assert!(decode(&[]).is_empty());
+
+    // 2 bytes per run (vs. 16 with `usize` counts on a 64-bit host)
+    let unoptimized: Vec<UsizeRun> = runs
+        .iter()
+        .map(|r| UsizeRun {
+            byte: r.byte,
+            len: r.len.usize(),
+        })
+        .collect();
+    assert_eq!(size_of_val(&runs[0]), 2);
+
+    println!(
+        "{} runs: {} bytes (vs. {} bytes with usize counts)",
+        runs.len(),
+        size_of_val(&runs[..]),
+        size_of_val(&unoptimized[..])
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn rle() {
+        super::main();
+    }
+}
@@ -0,0 +1,152 @@
+use core::mem::size_of;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Slot map with generational indexes: a key is a (slot index, generation) pair, so a stale key to a reused slot
+// is detected instead of silently aliasing a new value.
+// Both halves are bounded - index by capacity, generation by how many times a slot may be reused - so both shrink.
+
+const CAPACITY: usize = 10_000;
+const MAX_GENERATION: usize = 255;
+
+type SlotIdx = small_unsigned!(CAPACITY);
+type Generation = small_unsigned!(MAX_GENERATION);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Key {
+    idx: SlotIdx,
+    generation: Generation,
+}
+
+// Same key, unoptimized
+#[allow(dead_code)]
+pub struct UsizeKey {
+    idx: usize,
+    generation: usize,
+}
+
+struct Slot<T> {
+    generation: Generation,
+    value: Option<T>,
+    next_free: Option<SlotIdx>,
+}
+
+struct SlotMap<T> {
+    slots: Vec<Slot<T>>,
+    free_head: Option<SlotIdx>,
+}
+
+impl<T> SlotMap<T> {
+    fn new() -> Self {
+        SlotMap {
+            slots: Vec::new(),
+            free_head: None,
+        }
+    }
+
+    fn insert(&mut self, value: T) -> Option<Key> {
+        let idx = match self.free_head {
+            Some(idx) => {
+                let slot = &mut self.slots[idx.usize()];
+                self.free_head = slot.next_free.take();
+                slot.value = Some(value);
+                idx
+            }
+            None if self.slots.len() < CAPACITY => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    value: Some(value),
+                    next_free: None,
+                });
+                SlotIdx::checked_from(self.slots.len() - 1)
+            }
+            None => return None,
+        };
+
+        Some(Key {
+            idx,
+            generation: self.slots[idx.usize()].generation,
+        })
+    }
+
+    fn remove(&mut self, key: Key) -> Option<T> {
+        let slot = self.slots.get_mut(key.idx.usize())?;
+        if slot.generation != key.generation {
+            return None;
+        }
+
+        let value = slot.value.take()?;
+
+        // Retire slots whose generation can't grow further, so old keys can never alias
+        if slot.generation.usize() < MAX_GENERATION {
+            slot.generation = Generation::checked_from(slot.generation.usize() + 1);
+            slot.next_free = self.free_head;
+            self.free_head = Some(key.idx);
+        }
+
+        Some(value)
+    }
+
+    fn get(&self, key: Key) -> Option<&T> {
+        self.slots
+            .get(key.idx.usize())
+            .filter(|slot| slot.generation == key.generation)
+            .and_then(|slot| slot.value.as_ref())
+    }
+}
+
+fn main() {
+    let mut map = SlotMap::new();
+
+    let a = map.insert("a").unwrap();
+    let b = map.insert("b").unwrap();
+    assert_eq!(map.get(a), Some(&"a"));
+    assert_eq!(map.get(b), Some(&"b"));
+
+    // Removed key goes stale, its slot is reused under a new generation
+    assert_eq!(map.remove(a), Some("a"));
+    assert_eq!(map.get(a), None);
+    assert_eq!(map.remove(a), None);
+
+    let c = map.insert("c").unwrap();
+    assert_eq!(c.idx, a.idx);
+    assert_ne!(c.generation, a.generation);
+    assert_eq!(map.get(a), None);
+    assert_eq!(map.get(c), Some(&"c"));
+
+    // A slot churned through every generation is retired
+    let mut key = map.insert("churn").unwrap();
+    let churned_idx = key.idx;
+    for _ in 0..MAX_GENERATION {
+        map.remove(key).unwrap();
+        key = map.insert("churn").unwrap();
+        assert_eq!(key.idx, churned_idx);
+    }
+    assert_eq!(key.generation.usize(), MAX_GENERATION);
+    map.remove(key).unwrap();
+    assert_ne!(map.insert("fresh").unwrap().idx, churned_idx);
+
+    // Fill to capacity
+    while map.insert("fill").is_some() {}
+    assert_eq!(map.slots.len(), CAPACITY);
+
+    // 3 bytes of payload (2 + 1), padded to 4, vs. 16 on a 64-bit host
+    assert_eq!(size_of::<Key>(), 4);
+    assert_eq!(size_of::<Option<Key>>(), 6);
+
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(size_of::<UsizeKey>(), 16);
+
+    println!(
+        "key: {} bytes (vs. {} bytes with usize fields)",
+        size_of::<Key>(),
+        size_of::<UsizeKey>()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn slotmap() {
+        super::main();
+    }
+}
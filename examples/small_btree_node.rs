@@ -0,0 +1,219 @@
+use core::mem::size_of;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// B-tree with nodes in an arena: each node stores a key count and child pointers (arena indexes).
+// Key counts are bounded by node capacity, child pointers by arena capacity, so both shrink.
+// Across thousands of nodes (e.g. a database page cache), the savings add up.
+
+// Minimum degree: non-root nodes hold `T - 1..=2T - 1` keys
+const T: usize = 8;
+const MAX_KEYS: usize = (2 * T) - 1;
+const MAX_NODES: usize = 1_024;
+
+type KeyCount = small_unsigned!(MAX_KEYS);
+type ChildPtr = small_unsigned!(MAX_NODES);
+
+#[derive(Clone)]
+struct Node {
+    keys: [u64; MAX_KEYS],
+    children: [ChildPtr; MAX_KEYS + 1],
+    len: KeyCount,
+    leaf: bool,
+}
+
+// Same node, unoptimized
+#[allow(dead_code)]
+struct UsizeNode {
+    keys: [u64; MAX_KEYS],
+    children: [usize; MAX_KEYS + 1],
+    len: usize,
+    leaf: bool,
+}
+
+impl Node {
+    fn new(leaf: bool) -> Self {
+        Node {
+            keys: [0; MAX_KEYS],
+            children: [0; MAX_KEYS + 1],
+            len: 0,
+            leaf,
+        }
+    }
+
+    fn keys(&self) -> &[u64] {
+        &self.keys[..self.len.usize()]
+    }
+
+    fn is_full(&self) -> bool {
+        self.len.usize() == MAX_KEYS
+    }
+}
+
+struct BTree {
+    nodes: Vec<Node>,
+    root: ChildPtr,
+}
+
+impl BTree {
+    fn new() -> Self {
+        BTree {
+            nodes: vec![Node::new(true)],
+            root: 0,
+        }
+    }
+
+    fn alloc(&mut self, node: Node) -> ChildPtr {
+        assert!(self.nodes.len() < MAX_NODES, "arena full");
+        self.nodes.push(node);
+        ChildPtr::checked_from(self.nodes.len() - 1)
+    }
+
+    fn node(&self, ptr: ChildPtr) -> &Node {
+        &self.nodes[..][ptr.as_index()]
+    }
+
+    fn node_mut(&mut self, ptr: ChildPtr) -> &mut Node {
+        &mut self.nodes[..][ptr.as_index()]
+    }
+
+    // Split full child `i` of `parent`, moving its median key up
+    fn split_child(&mut self, parent: ChildPtr, i: usize) {
+        let full = self.node(parent).children[i];
+        let left = self.node(full).clone();
+
+        let mut right = Node::new(left.leaf);
+        right.keys[..(T - 1)].copy_from_slice(&left.keys[T..]);
+        right.children[..T].copy_from_slice(&left.children[T..]);
+        right.len = KeyCount::checked_from(T - 1);
+
+        let right = self.alloc(right);
+        self.node_mut(full).len = KeyCount::checked_from(T - 1);
+
+        let p = self.node_mut(parent);
+        let len = p.len.usize();
+        p.keys.copy_within(i..len, i + 1);
+        p.children.copy_within((i + 1)..(len + 1), i + 2);
+        p.keys[i] = left.keys[T - 1];
+        p.children[i + 1] = right;
+        p.len = KeyCount::checked_from(len + 1);
+    }
+
+    // Split full nodes on the way down (CLRS), so the leaf always has room
+    fn insert(&mut self, key: u64) {
+        if self.node(self.root).is_full() {
+            let mut root = Node::new(false);
+            root.children[0] = self.root;
+            self.root = self.alloc(root);
+            self.split_child(self.root, 0);
+        }
+
+        let mut ptr = self.root;
+        loop {
+            let i = self.node(ptr).keys().partition_point(|k| *k < key);
+
+            if self.node(ptr).leaf {
+                let n = self.node_mut(ptr);
+                let len = n.len.usize();
+                n.keys.copy_within(i..len, i + 1);
+                n.keys[i] = key;
+                n.len = KeyCount::checked_from(len + 1);
+                return;
+            }
+
+            let mut i = i;
+            if self.node(self.node(ptr).children[i]).is_full() {
+                self.split_child(ptr, i);
+                if key > self.node(ptr).keys[i] {
+                    i += 1;
+                }
+            }
+            ptr = self.node(ptr).children[i];
+        }
+    }
+
+    // Node pointers visited from the root, if `key` is present
+    fn search(&self, key: u64) -> Option<Vec<ChildPtr>> {
+        let mut path = vec![self.root];
+        loop {
+            let n = self.node(*path.last()?);
+            let i = n.keys().partition_point(|k| *k < key);
+
+            if n.keys().get(i) == Some(&key) {
+                return Some(path);
+            }
+            if n.leaf {
+                return None;
+            }
+            path.push(n.children[i]);
+        }
+    }
+
+    // In-order keys, and depth of every leaf
+    fn walk(&self, ptr: ChildPtr, depth: usize, keys: &mut Vec<u64>, leaf_depths: &mut Vec<usize>) {
+        let n = self.node(ptr);
+        if ptr != self.root {
+            assert!(n.len.usize() >= (T - 1));
+        }
+
+        if n.leaf {
+            keys.extend_from_slice(n.keys());
+            leaf_depths.push(depth);
+            return;
+        }
+
+        for (i, key) in n.keys().iter().enumerate() {
+            self.walk(n.children[i], depth + 1, keys, leaf_depths);
+            keys.push(*key);
+        }
+        self.walk(n.children[n.len.usize()], depth + 1, keys, leaf_depths);
+    }
+}
+
+fn main() {
+    const NUM_KEYS: u64 = 2_000;
+
+    // Insert in a scrambled order (7919 is coprime to `NUM_KEYS`)
+    let mut tree = BTree::new();
+    for i in 0..NUM_KEYS {
+        tree.insert(((i * 7919) % NUM_KEYS) * 10);
+    }
+
+    // Every key is found, with a path no deeper than the tree
+    let mut max_depth = 0;
+    for i in 0..NUM_KEYS {
+        let path = tree.search(i * 10).expect("key missing");
+        max_depth = max_depth.max(path.len());
+
+        assert!(tree.search((i * 10) + 5).is_none());
+    }
+    assert!(tree.search(NUM_KEYS * 10).is_none());
+
+    // Sorted, balanced, and every non-root node at least half full
+    let (mut keys, mut leaf_depths) = (Vec::new(), Vec::new());
+    tree.walk(tree.root, 1, &mut keys, &mut leaf_depths);
+    assert_eq!(keys, (0..NUM_KEYS).map(|i| i * 10).collect::<Vec<_>>());
+    assert!(leaf_depths.iter().all(|d| *d == max_depth));
+    assert!(tree.nodes.len() <= MAX_NODES);
+
+    // Key count and child pointers: 1 + (2 * 16) bytes (vs. 8 + (8 * 16) with `usize`)
+    assert_eq!(size_of::<KeyCount>(), 1);
+    assert_eq!(size_of::<ChildPtr>(), 2);
+    assert!(size_of::<Node>() < size_of::<UsizeNode>());
+
+    println!(
+        "{} keys in {} nodes, depth {}, node: {} bytes (vs. {} bytes with usize)",
+        keys.len(),
+        tree.nodes.len(),
+        max_depth,
+        size_of::<Node>(),
+        size_of::<UsizeNode>()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn small_btree_node() {
+        super::main();
+    }
+}
@@ -0,0 +1,168 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Fixed-capacity, indexed min-heap (e.g. the frontier of Dijkstra's algorithm).
+// The heap stores item handles, and a position map tracks where each item sits in the heap for `decrease_key`.
+// Both are arrays of indexes bounded by capacity, so they're size-optimized.
+
+const CAPACITY: usize = 1_024;
+
+type Idx = small_unsigned!(CAPACITY);
+
+struct SmallHeap {
+    // Heap-ordered item handles
+    heap: [Idx; CAPACITY],
+
+    // Item -> position in `heap`, if queued
+    pos: [Option<Idx>; CAPACITY],
+
+    // Item -> priority
+    prio: [u32; CAPACITY],
+
+    len: usize,
+}
+
+impl SmallHeap {
+    fn new() -> Self {
+        SmallHeap {
+            heap: [0; CAPACITY],
+            pos: [None; CAPACITY],
+            prio: [0; CAPACITY],
+            len: 0,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn contains(&self, item: usize) -> bool {
+        self.pos[item].is_some()
+    }
+
+    fn push(&mut self, item: usize, prio: u32) {
+        assert!(self.len < CAPACITY);
+        assert!(!self.contains(item));
+
+        self.heap[self.len] = Idx::checked_from(item);
+        self.pos[item] = Some(Idx::checked_from(self.len));
+        self.prio[item] = prio;
+        self.len += 1;
+        self.sift_up(self.len - 1);
+    }
+
+    fn pop(&mut self) -> Option<(usize, u32)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let top = self.heap[0].usize();
+        self.len -= 1;
+        self.swap(0, self.len);
+        self.pos[top] = None;
+        self.sift_down(0);
+
+        Some((top, self.prio[top]))
+    }
+
+    fn decrease_key(&mut self, item: usize, prio: u32) {
+        let i = self.pos[item].expect("item not queued").usize();
+        assert!(prio <= self.prio[item]);
+
+        self.prio[item] = prio;
+        self.sift_up(i);
+    }
+
+    fn key(&self, i: usize) -> u32 {
+        self.prio[self.heap[i].usize()]
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.pos[self.heap[i].usize()] = Some(Idx::checked_from(i));
+        self.pos[self.heap[j].usize()] = Some(Idx::checked_from(j));
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.key(i) >= self.key(parent) {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let (left, right) = ((2 * i) + 1, (2 * i) + 2);
+            let mut min = i;
+
+            if (left < self.len) && (self.key(left) < self.key(min)) {
+                min = left;
+            }
+            if (right < self.len) && (self.key(right) < self.key(min)) {
+                min = right;
+            }
+            if min == i {
+                break;
+            }
+
+            self.swap(i, min);
+            i = min;
+        }
+    }
+}
+
+fn main() {
+    let mut heap = SmallHeap::new();
+
+    // Pseudo-random priorities
+    let mut prio = 7_u32;
+    for item in 0..CAPACITY {
+        prio = prio.wrapping_mul(1_103_515_245).wrapping_add(12_345) % 100_000;
+        heap.push(item, prio);
+    }
+
+    // Move a few items to the front
+    heap.decrease_key(500, 0);
+    heap.decrease_key(1_023, 1);
+    assert!(heap.contains(500));
+
+    assert_eq!(heap.pop(), Some((500, 0)));
+    assert_eq!(heap.pop(), Some((1_023, 1)));
+    assert!(!heap.contains(500));
+
+    // Remaining items come out in priority order
+    let mut last = 0;
+    let mut popped = 2;
+    while let Some((_, p)) = heap.pop() {
+        assert!(p >= last);
+        last = p;
+        popped += 1;
+    }
+
+    assert_eq!(popped, CAPACITY);
+    assert!(heap.is_empty());
+
+    // Index storage: 2 bytes per heap slot, 4 bytes per position (vs. 8 and 16 with `usize`)
+    let unoptimized_heap = [0_usize; CAPACITY];
+    let unoptimized_pos = [None::<usize>; CAPACITY];
+    assert_eq!(size_of_val(&heap.heap), 2 * CAPACITY);
+    assert_eq!(size_of_val(&heap.pos), 4 * CAPACITY);
+
+    println!(
+        "index storage: {} bytes (vs. {} bytes with usize)",
+        size_of_val(&heap.heap) + size_of_val(&heap.pos),
+        size_of_val(&unoptimized_heap) + size_of_val(&unoptimized_pos),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn small_heap() {
+        super::main();
+    }
+}
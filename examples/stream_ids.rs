@@ -0,0 +1,140 @@
+use core::mem::size_of;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Connection table for a multiplexed protocol, where each connection carries a bounded number of streams.
+// Stream IDs index into the table and are stored in every entry (free list links), so they're size-optimized.
+
+const MAX_STREAMS: usize = 1_000;
+
+type StreamId = small_unsigned!(MAX_STREAMS);
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum State {
+    Idle,
+    Open,
+    HalfClosed,
+}
+
+#[derive(Copy, Clone)]
+pub struct Stream {
+    pub state: State,
+    pub window: u16,
+    pub next_free: Option<StreamId>,
+}
+
+// Same entry, unoptimized
+#[allow(dead_code)]
+pub struct UsizeStream {
+    pub state: State,
+    pub window: u16,
+    pub next_free: Option<usize>,
+}
+
+pub struct ConnectionTable {
+    streams: [Stream; MAX_STREAMS],
+    free_head: Option<StreamId>,
+    open_cnt: usize,
+}
+
+impl ConnectionTable {
+    fn new() -> Self {
+        let mut streams = [Stream {
+            state: State::Idle,
+            window: 0,
+            next_free: None,
+        }; MAX_STREAMS];
+
+        for (i, s) in streams.iter_mut().enumerate().take(MAX_STREAMS - 1) {
+            s.next_free = Some(StreamId::checked_from(i + 1));
+        }
+
+        ConnectionTable {
+            streams,
+            free_head: Some(StreamId::checked_from(0)),
+            open_cnt: 0,
+        }
+    }
+
+    fn open(&mut self, window: u16) -> Option<StreamId> {
+        let id = self.free_head?;
+        let s = &mut self.streams[id.usize()];
+
+        self.free_head = s.next_free.take();
+        s.state = State::Open;
+        s.window = window;
+        self.open_cnt += 1;
+
+        Some(id)
+    }
+
+    fn half_close(&mut self, id: StreamId) {
+        let s = &mut self.streams[id.usize()];
+        assert_eq!(s.state, State::Open);
+        s.state = State::HalfClosed;
+    }
+
+    fn close(&mut self, id: StreamId) {
+        let s = &mut self.streams[id.usize()];
+        assert_ne!(s.state, State::Idle);
+
+        s.state = State::Idle;
+        s.window = 0;
+        s.next_free = self.free_head;
+        self.free_head = Some(id);
+        self.open_cnt -= 1;
+    }
+
+    fn get(&self, id: StreamId) -> &Stream {
+        &self.streams[id.usize()]
+    }
+
+    fn open_count(&self) -> usize {
+        self.open_cnt
+    }
+}
+
+fn main() {
+    let mut table = ConnectionTable::new();
+
+    // Fill every slot
+    let ids: Vec<StreamId> = (0..MAX_STREAMS)
+        .map(|i| table.open((i % 100) as u16).unwrap())
+        .collect();
+
+    assert_eq!(table.open_count(), MAX_STREAMS);
+    assert!(table.open(0).is_none());
+    assert_eq!(ids[42].usize(), 42);
+    assert_eq!(table.get(ids[42]).window, 42);
+
+    // Closing frees the ID for reuse
+    table.half_close(ids[7]);
+    assert_eq!(table.get(ids[7]).state, State::HalfClosed);
+    table.close(ids[7]);
+    assert_eq!(table.open_count(), MAX_STREAMS - 1);
+
+    let reused = table.open(500).unwrap();
+    assert_eq!(reused, ids[7]);
+    assert_eq!(table.get(reused).state, State::Open);
+
+    // Footprint
+    assert_eq!(size_of::<StreamId>(), 2);
+
+    #[cfg(target_pointer_width = "64")]
+    assert_eq!(size_of::<UsizeStream>() - size_of::<Stream>(), 16);
+
+    println!(
+        "entry: {} bytes (vs. {} bytes with usize IDs), table: {} bytes (vs. {} bytes)",
+        size_of::<Stream>(),
+        size_of::<UsizeStream>(),
+        size_of::<Stream>() * MAX_STREAMS,
+        size_of::<UsizeStream>() * MAX_STREAMS,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn stream_ids() {
+        super::main();
+    }
+}
@@ -0,0 +1,115 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned, SmallUnsigned};
+
+// Suffix array: every suffix start of a text, sorted by the suffix it starts.
+// Substring search is then a binary search, and each occurrence is a contiguous run of the array.
+// Positions are bounded by the text length, so the array shrinks to the smallest type that can hold them.
+
+const TEXT_LEN: usize = 1_000;
+
+type Pos = small_unsigned!(TEXT_LEN);
+
+struct SuffixArray<'a> {
+    text: &'a [u8],
+    sa: Vec<Pos>,
+}
+
+impl<'a> SuffixArray<'a> {
+    // Naive construction, `O(n^2 log n)` worst case, fine for short texts
+    fn new(text: &'a [u8]) -> Self {
+        assert!(text.len() <= TEXT_LEN);
+
+        let mut sa: Vec<Pos> = (0..text.len()).map(Pos::checked_from).collect();
+        sa.sort_by(|a, b| text[a.usize()..].cmp(&text[b.usize()..]));
+
+        SuffixArray { text, sa }
+    }
+
+    fn suffix(&self, pos: Pos) -> &[u8] {
+        &self.text[pos.usize()..]
+    }
+
+    // Sorted start positions of every occurrence of `pattern`
+    fn find(&self, pattern: &[u8]) -> Vec<usize> {
+        let prefix = |pos: &Pos| {
+            let suffix = self.suffix(*pos);
+            &suffix[..pattern.len().min(suffix.len())]
+        };
+
+        let lo = self.sa.partition_point(|pos| prefix(pos) < pattern);
+        let hi = self.sa.partition_point(|pos| prefix(pos) <= pattern);
+
+        // Bulk widen the matching run, then sort by text order
+        let mut hits = vec![0; hi - lo];
+        Pos::widen_slice_into(&self.sa[lo..hi], &mut hits);
+        hits.sort_unstable();
+        hits
+    }
+
+    // Longest common prefix of adjacent suffixes, in suffix array order
+    fn lcp(&self) -> Vec<Pos> {
+        let mut lcp = vec![Pos::checked_from(0); self.sa.len()];
+        for (l, pair) in lcp.iter_mut().skip(1).zip(self.sa.windows(2)) {
+            let (a, b) = (self.suffix(pair[0]), self.suffix(pair[1]));
+            *l = Pos::checked_from(a.iter().zip(b).take_while(|(x, y)| x == y).count());
+        }
+        lcp
+    }
+}
+
+fn main() {
+    // Classic example
+    let sa = SuffixArray::new(b"banana");
+    let positions: Vec<usize> = sa.sa.iter().map(|pos| pos.usize()).collect();
+    assert_eq!(positions, vec![5, 3, 1, 0, 4, 2]);
+    assert_eq!(sa.find(b"ana"), vec![1, 3]);
+    assert_eq!(sa.find(b"nab"), vec![]);
+    assert_eq!(sa.find(b""), vec![0, 1, 2, 3, 4, 5]);
+    assert_eq!(sa.lcp().iter().map(|l| l.usize()).max(), Some(3));
+
+    // First suffix in order starts with the smallest byte
+    assert_eq!(b"banana"[sa.sa[0].as_index()], b'a');
+
+    // Longer text, checked against a linear scan
+    let text: Vec<u8> = b"she sells sea shells by the sea shore. "
+        .iter()
+        .cycle()
+        .take(TEXT_LEN)
+        .copied()
+        .collect();
+    let sa = SuffixArray::new(&text);
+
+    for pattern in [&b"sea"[..], b"shells", b"e s", b"shore. she", b"seashell"] {
+        let expected: Vec<usize> = (0..text.len())
+            .filter(|i| text[*i..].starts_with(pattern))
+            .collect();
+        assert_eq!(sa.find(pattern), expected);
+    }
+
+    // Adjacent suffixes are sorted: each shares its LCP and then compares greater
+    let lcp = sa.lcp();
+    for i in 1..sa.sa.len() {
+        let (a, b) = (sa.suffix(sa.sa[i - 1]), sa.suffix(sa.sa[i]));
+        assert_eq!(a[..lcp[i].usize()], b[..lcp[i].usize()]);
+        assert!(a < b);
+    }
+
+    // Positions: 2 bytes each (vs. 8 with `usize`)
+    let unoptimized = vec![0_usize; sa.sa.len()];
+    assert_eq!(size_of_val(&sa.sa[..]), 2 * TEXT_LEN);
+
+    println!(
+        "{} occurrences of \"sea\", suffix array: {} bytes (vs. {} bytes with usize)",
+        sa.find(b"sea").len(),
+        size_of_val(&sa.sa[..]),
+        size_of_val(&unoptimized[..])
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn suffix_array() {
+        super::main();
+    }
+}
@@ -0,0 +1,117 @@
+use core::mem::size_of_val;
+use smallnum::{small_unsigned_for_range, SmallUnsigned};
+
+// Time-series timestamps, stored as deltas from the previous sample.
+// Samples usually arrive at a steady rate, so deltas are bounded and fit a small type.
+// A gap too large for the delta type stores an escape value, and the full timestamp goes in a side table.
+
+// Delta offsets span `0..DELTA_RANGE`, the top one is reserved as the escape
+const DELTA_RANGE: usize = 256;
+const ESCAPE: usize = DELTA_RANGE - 1;
+
+type Delta = small_unsigned_for_range!(0..DELTA_RANGE);
+
+struct TsColumn {
+    first: Option<u64>,
+    deltas: Vec<Delta>,
+
+    // Absolute timestamps for escaped deltas, in order
+    full: Vec<u64>,
+}
+
+impl TsColumn {
+    fn encode(timestamps: &[u64]) -> Self {
+        let first = timestamps.first().copied();
+        let mut deltas = Vec::with_capacity(timestamps.len().saturating_sub(1));
+        let mut full = Vec::new();
+
+        for pair in timestamps.windows(2) {
+            let gap = pair[1] - pair[0];
+            if gap < (ESCAPE as u64) {
+                deltas.push(Delta::checked_from(gap as usize));
+            } else {
+                deltas.push(Delta::checked_from(ESCAPE));
+                full.push(pair[1]);
+            }
+        }
+
+        TsColumn {
+            first,
+            deltas,
+            full,
+        }
+    }
+
+    fn decode(&self) -> Vec<u64> {
+        let mut prev = match self.first {
+            Some(first) => first,
+            None => return Vec::new(),
+        };
+
+        let mut full = self.full.iter();
+        let mut out = vec![prev];
+
+        for delta in &self.deltas {
+            prev = if delta.is_type_max() {
+                *full.next().expect("escape without full timestamp")
+            } else {
+                prev + (delta.usize() as u64)
+            };
+            out.push(prev);
+        }
+
+        out
+    }
+
+    fn escapes(&self) -> usize {
+        self.full.len()
+    }
+}
+
+fn main() {
+    // 10ms cadence, with jitter, a stall, and a restart
+    let mut timestamps = Vec::new();
+    let mut t = 1_700_000_000_000_u64;
+    for i in 0..1_000_u64 {
+        t += match i {
+            300 => 5_000,
+            700 => 254,
+            701 => 255,
+            _ => 10 + (i % 3),
+        };
+        timestamps.push(t);
+    }
+
+    let col = TsColumn::encode(&timestamps);
+    assert_eq!(col.decode(), timestamps);
+
+    // Only gaps at or past the escape spill: the stall and the 255ms gap, not the 254ms one
+    assert_eq!(col.escapes(), 2);
+    assert!(col.deltas[299].is_type_max());
+    assert_eq!(col.deltas[699].usize(), 254);
+    assert!(col.deltas[700].is_type_max());
+
+    // Edge cases
+    assert_eq!(TsColumn::encode(&[]).decode(), vec![]);
+    assert_eq!(TsColumn::encode(&[42]).decode(), vec![42]);
+
+    // 1 byte per sample, plus 8 per escape (vs. 8 per sample storing raw timestamps)
+    let stored = size_of_val(&col.deltas[..]) + size_of_val(&col.full[..]);
+    let unoptimized = size_of_val(&timestamps[..]);
+    assert_eq!(stored, (timestamps.len() - 1) + (8 * col.escapes()));
+
+    println!(
+        "timestamps: {} bytes (vs. {} bytes raw), {} escapes",
+        stored,
+        unoptimized,
+        col.escapes()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn ts_deltas() {
+        super::main();
+    }
+}
@@ -0,0 +1,161 @@
+/*!
+Attribute and derive macros for [`smallnum`](https://docs.rs/smallnum). Use via `smallnum`'s `derive` feature, not directly.
+*/
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Expr, ExprLit, Fields, Lit, Meta};
+
+// Struct Rewriting ----------------------------------------------------------------------------------------------------
+
+/// Shrink bounded fields of a struct: every field annotated `#[small(max = "BOUND")]` has its type replaced with
+/// `small_unsigned!(BOUND)`, and gets `usize`-based getter and setter methods (e.g. `len()` and `set_len()`).
+/// `BOUND` may be given as a string or directly as an expression, and must be const.
+///
+/// This is an attribute macro rather than a derive, since derives can't modify the item they're attached to.
+/// Getters copy the field before widening, so the struct can be `#[repr(packed)]` for extreme cases.
+#[proc_macro_attribute]
+pub fn small_fields(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(Span::call_site(), "`small_fields` takes no arguments")
+            .to_compile_error()
+            .into();
+    }
+
+    let mut input = parse_macro_input!(item as DeriveInput);
+    match expand(&mut input) {
+        Ok(accessors) => quote! { #input #accessors }.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+fn expand(input: &mut DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &mut input.data {
+        Data::Struct(data) => match &mut data.fields {
+            Fields::Named(fields) => &mut fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &input.ident,
+                    "`small_fields` requires a struct with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`small_fields` can only be applied to structs",
+            ))
+        }
+    };
+
+    let mut accessors = Vec::new();
+    for field in fields.iter_mut() {
+        let pos = match field.attrs.iter().position(|a| a.path().is_ident("small")) {
+            Some(pos) => pos,
+            None => continue,
+        };
+
+        let max = parse_max(&field.attrs.remove(pos).meta)?;
+        field.ty = syn::parse_quote! { ::smallnum::small_unsigned!(#max) };
+
+        // Named fields always have an ident
+        let (vis, name) = (&field.vis, field.ident.as_ref().unwrap());
+        let setter = format_ident!("set_{}", name);
+        let getter_doc = format!("`{}` widened to `usize`.", name);
+        let setter_doc = format!(
+            "Narrow `val` into `{}`. Panics if `val` exceeds max for the selected type.",
+            name
+        );
+
+        accessors.push(quote! {
+            #[doc = #getter_doc]
+            #vis fn #name(&self) -> usize {
+                let val = self.#name;
+                ::smallnum::SmallUnsigned::usize(&val)
+            }
+
+            #[doc = #setter_doc]
+            #vis fn #setter(&mut self, val: usize) {
+                self.#name = ::smallnum::SmallUnsigned::checked_from(val);
+            }
+        });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            #(#accessors)*
+        }
+    })
+}
+
+// Parse the bound out of `#[small(max = "BOUND")]` or `#[small(max = BOUND)]`.
+fn parse_max(meta: &Meta) -> syn::Result<Expr> {
+    let usage = "expected `#[small(max = \"BOUND\")]`";
+
+    let list = match meta {
+        Meta::List(list) => list,
+        _ => return Err(syn::Error::new_spanned(meta, usage)),
+    };
+
+    let nv: syn::MetaNameValue = list.parse_args()?;
+    if !nv.path.is_ident("max") {
+        return Err(syn::Error::new_spanned(&nv.path, usage));
+    }
+
+    match nv.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => s.parse(),
+        expr => Ok(expr),
+    }
+}
+
+// Layout Introspection -----------------------------------------------------------------------------------------------
+
+/// Generate associated consts describing the packed layout of a struct whose fields all implement `SmallUnsigned`:
+/// `FIELD_LABELS`, a `&'static [SmallUnsignedLabel]` in declaration order, and `PACKED_SIZE`, the sum of field sizes.
+/// Labels are resolved to fixed widths, so a `usize` field is labeled with the host's width (e.g. `U64`).
+#[proc_macro_derive(SmallLayout)]
+pub fn small_layout(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return syn::Error::new_spanned(
+                &input.ident,
+                "`SmallLayout` can only be derived for structs",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+
+    let tys: Vec<_> = fields.iter().map(|f| &f.ty).collect();
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Label of each field, in declaration order.
+            pub const FIELD_LABELS: &'static [::smallnum::SmallUnsignedLabel] = &[
+                #(
+                    ::smallnum::SmallUnsignedLabel::new(
+                        ::smallnum::bits_max(<#tys as ::smallnum::SmallUnsigned>::BITS) as usize
+                    ),
+                )*
+            ];
+
+            /// Size of all fields laid out back-to-back with no padding, in bytes.
+            pub const PACKED_SIZE: usize = 0 #( + <#tys as ::smallnum::SmallUnsigned>::BYTES )*;
+        }
+    }
+    .into()
+}
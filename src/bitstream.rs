@@ -0,0 +1,206 @@
+use crate::{SmallNumError, SmallUnsigned};
+
+// Bit-packed Streams --------------------------------------------------------------------------------------------------
+
+// Max value representable in `n_bits` bits, `n_bits <= usize::BITS`.
+const fn bits_mask(n_bits: u32) -> usize {
+    match n_bits {
+        0 => 0,
+        _ => usize::MAX >> (usize::BITS - n_bits),
+    }
+}
+
+/// Writes small values at arbitrary bit offsets into a borrowed buffer, least significant bit first
+/// (bit `i` of the stream is bit `i % 8` of byte `i / 8`).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{BitReader, BitWriter};
+///
+/// let mut buf = [0_u8; 2];
+///
+/// let mut writer = BitWriter::new(&mut buf);
+/// writer.write_bits(5_u8, 3).unwrap();
+/// writer.write_bits(300_u16, 9).unwrap();
+/// assert_eq!(writer.bit_pos(), 12);
+///
+/// let mut reader = BitReader::new(&buf);
+/// assert_eq!(reader.read_bits::<u8>(3), Ok(5));
+/// assert_eq!(reader.read_bits::<u16>(9), Ok(300));
+/// ```
+#[derive(Debug)]
+pub struct BitWriter<'a> {
+    buf: &'a mut [u8],
+    bit_pos: usize,
+}
+
+impl<'a> BitWriter<'a> {
+    /// Start writing at the first bit of `buf`.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        BitWriter { buf, bit_pos: 0 }
+    }
+
+    /// Number of bits written so far.
+    pub fn bit_pos(&self) -> usize {
+        self.bit_pos
+    }
+
+    /// Write the low `n_bits` of `val`. Panics if `n_bits` exceeds `T::BITS`.
+    /// Errors, writing nothing, if `val` doesn't fit in `n_bits` or the buffer doesn't have `n_bits` left
+    /// (`OutOfBound` reports the `n_bits` mask as the bound, `CapacityExceeded` the buffer's capacity in bits).
+    pub fn write_bits<T: SmallUnsigned>(
+        &mut self,
+        val: T,
+        n_bits: u32,
+    ) -> Result<(), SmallNumError> {
+        assert!(n_bits <= T::BITS);
+
+        let (val, bound) = (val.usize(), bits_mask(n_bits));
+        if val > bound {
+            return Err(SmallNumError::OutOfBound { value: val, bound });
+        }
+
+        let end = self.bit_pos + (n_bits as usize);
+        if end > (self.buf.len() * 8) {
+            return Err(SmallNumError::CapacityExceeded {
+                capacity: self.buf.len() * 8,
+            });
+        }
+
+        for i in 0..(n_bits as usize) {
+            let (pos, mask) = (self.bit_pos + i, 1 << ((self.bit_pos + i) % 8));
+            if ((val >> i) & 1) == 1 {
+                self.buf[pos / 8] |= mask;
+            } else {
+                self.buf[pos / 8] &= !mask;
+            }
+        }
+
+        self.bit_pos = end;
+        Ok(())
+    }
+}
+
+/// Reads small values at arbitrary bit offsets from a borrowed buffer, least significant bit first.
+/// Inverse of [`BitWriter`].
+#[derive(Debug, Clone)]
+pub struct BitReader<'a> {
+    buf: &'a [u8],
+    bit_pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Start reading at the first bit of `buf`.
+    pub fn new(buf: &'a [u8]) -> Self {
+        BitReader { buf, bit_pos: 0 }
+    }
+
+    /// Number of bits read so far.
+    pub fn bit_pos(&self) -> usize {
+        self.bit_pos
+    }
+
+    /// Read the next `n_bits` into `T`. Panics if `n_bits` exceeds `T::BITS`.
+    /// Errors, reading nothing, if the buffer doesn't have `n_bits` left.
+    pub fn read_bits<T: SmallUnsigned>(&mut self, n_bits: u32) -> Result<T, SmallNumError> {
+        assert!(n_bits <= T::BITS);
+
+        let end = self.bit_pos + (n_bits as usize);
+        if end > (self.buf.len() * 8) {
+            return Err(SmallNumError::Truncated {
+                needed: end.div_ceil(8),
+                available: self.buf.len(),
+            });
+        }
+
+        let mut val = 0;
+        for i in 0..(n_bits as usize) {
+            let pos = self.bit_pos + i;
+            val |= (((self.buf[pos / 8] >> (pos % 8)) & 1) as usize) << i;
+        }
+
+        self.bit_pos = end;
+        Ok(T::checked_from(val))
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::{small_unsigned, BitReader, BitWriter, SmallNumError};
+
+    #[test]
+    fn bits_round_trip() {
+        type Nibble = small_unsigned!(15);
+        type Wide = small_unsigned!(100_000);
+
+        let mut buf = [0_u8; 8];
+        let mut writer = BitWriter::new(&mut buf);
+
+        writer.write_bits::<Nibble>(0xa, 4).unwrap();
+        writer.write_bits(1_u8, 1).unwrap();
+        writer.write_bits(0_u8, 0).unwrap();
+        writer.write_bits::<Nibble>(0x3, 2).unwrap();
+        writer.write_bits::<Wide>(100_000, 17).unwrap();
+        writer.write_bits(0xbeef_u16, 16).unwrap();
+        assert_eq!(writer.bit_pos(), 40);
+
+        // LSB-first: 0xa in bits 0..4, 1 in bit 4, 0b11 in bits 5..7
+        assert_eq!(buf[0], 0b0111_1010);
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(reader.read_bits::<Nibble>(4), Ok(0xa));
+        assert_eq!(reader.read_bits::<u8>(1), Ok(1));
+        assert_eq!(reader.read_bits::<u8>(0), Ok(0));
+        assert_eq!(reader.read_bits::<Nibble>(2), Ok(0x3));
+        assert_eq!(reader.read_bits::<Wide>(17), Ok(100_000));
+        assert_eq!(reader.read_bits::<u16>(16), Ok(0xbeef));
+        assert_eq!(reader.bit_pos(), 40);
+    }
+
+    #[test]
+    fn bits_overwrite() {
+        let mut buf = [0xff_u8; 1];
+        let mut writer = BitWriter::new(&mut buf);
+
+        writer.write_bits(0_u8, 3).unwrap();
+        assert_eq!(buf[0], 0b1111_1000);
+    }
+
+    #[test]
+    fn bits_errors() {
+        let mut buf = [0_u8; 1];
+        let mut writer = BitWriter::new(&mut buf);
+
+        assert_eq!(
+            writer.write_bits(8_u8, 3),
+            Err(SmallNumError::OutOfBound { value: 8, bound: 7 })
+        );
+        writer.write_bits(0x7f_u8, 7).unwrap();
+        assert_eq!(
+            writer.write_bits(0_u8, 2),
+            Err(SmallNumError::CapacityExceeded { capacity: 8 })
+        );
+        assert_eq!(writer.bit_pos(), 7);
+
+        let mut reader = BitReader::new(&buf);
+        assert_eq!(reader.read_bits::<u8>(7), Ok(0x7f));
+        assert_eq!(
+            reader.read_bits::<u8>(2),
+            Err(SmallNumError::Truncated {
+                needed: 2,
+                available: 1
+            })
+        );
+        assert_eq!(reader.bit_pos(), 7);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bits_too_wide() {
+        let mut buf = [0_u8; 4];
+        BitWriter::new(&mut buf).write_bits(1_u8, 9).unwrap();
+    }
+}
@@ -0,0 +1,217 @@
+use core::num::Saturating;
+use core::ops::{Add, Sub};
+
+use crate::SmallUnsigned;
+
+// Bounded Saturation --------------------------------------------------------------------------------------------------
+
+/// A small unsigned value whose arithmetic saturates at the logical bound `MAX`, rather than at the max of the
+/// underlying primitive `T`. Use [`bounded_saturating!`](crate::bounded_saturating) to select `T` from `MAX`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{bounded_saturating, BoundedSaturating};
+///
+/// const MAX_HP: usize = 200;
+/// type Hp = bounded_saturating!(MAX_HP);
+///
+/// let hp = Hp::new(150) + Hp::new(100);
+/// assert_eq!(hp.usize(), MAX_HP); // Not 250, although the underlying `u8` could hold it
+///
+/// let hp = hp - Hp::new(MAX_HP) - Hp::new(1);
+/// assert_eq!(hp.usize(), 0);
+/// ```
+///
+/// The bound must fit the underlying primitive:
+///
+/// ```compile_fail,E0080
+/// use smallnum::BoundedSaturating;
+///
+/// let too_big = BoundedSaturating::<u8, 300>::new(256);
+/// ```
+///
+/// With the `serde` feature, serializes as the underlying primitive, and rejects values above `MAX` on deserialization.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
+pub struct BoundedSaturating<T: SmallUnsigned, const MAX: usize>(T);
+
+impl<T: SmallUnsigned, const MAX: usize> BoundedSaturating<T, MAX> {
+    // Evaluated wherever it's referenced, so a `MAX` too large for `T` fails to compile rather than panicking
+    const MAX_FITS: () = assert!(
+        MAX <= T::MAX,
+        "BoundedSaturating: MAX exceeds max of underlying primitive"
+    );
+
+    /// **Downcast:** Convert input `usize`, clamped to `MAX`, into a bounded value.
+    /// Fails to compile if `MAX` exceeds max for the underlying unsigned primitive.
+    pub fn new(num: usize) -> Self {
+        let () = Self::MAX_FITS;
+        BoundedSaturating(T::checked_from(num.min(MAX)))
+    }
+
+    /// Underlying small value.
+    pub fn get(self) -> T {
+        self.0
+    }
+
+    /// **Upcast:** Get value as host register-width unsigned (e.g. `usize`)
+    pub fn usize(&self) -> usize {
+        self.0.usize()
+    }
+}
+
+impl<T: SmallUnsigned, const MAX: usize> Add for BoundedSaturating<T, MAX> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.usize().saturating_add(rhs.usize()))
+    }
+}
+
+impl<T: SmallUnsigned, const MAX: usize> Sub for BoundedSaturating<T, MAX> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.usize().saturating_sub(rhs.usize()))
+    }
+}
+
+/// Drops the logical bound, arithmetic on the result saturates at the max of `T`.
+impl<T: SmallUnsigned, const MAX: usize> From<BoundedSaturating<T, MAX>> for Saturating<T> {
+    fn from(bounded: BoundedSaturating<T, MAX>) -> Self {
+        Saturating(bounded.0)
+    }
+}
+
+/// Restores the logical bound, clamping to `MAX`.
+impl<T: SmallUnsigned, const MAX: usize> From<Saturating<T>> for BoundedSaturating<T, MAX> {
+    fn from(sat: Saturating<T>) -> Self {
+        Self::new(sat.0.usize())
+    }
+}
+
+// Manual, so a deserialized value can't exceed the logical bound
+#[cfg(feature = "serde")]
+impl<'de, T, const MAX: usize> serde::Deserialize<'de> for BoundedSaturating<T, MAX>
+where
+    T: SmallUnsigned + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let () = Self::MAX_FITS;
+        let val = T::deserialize(deserializer)?;
+        if val.usize() > MAX {
+            return Err(serde::de::Error::custom(format_args!(
+                "{} exceeds bound {}",
+                val.usize(),
+                MAX
+            )));
+        }
+
+        Ok(BoundedSaturating(val))
+    }
+}
+
+/// Return a [`BoundedSaturating`](crate::BoundedSaturating) type saturating at `max`, stored in the smallest
+/// unsigned type capable of representing it.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::bounded_saturating;
+/// use core::mem::size_of;
+///
+/// assert_eq!(size_of::<bounded_saturating!(200)>(), 1);
+/// assert_eq!(size_of::<bounded_saturating!(1_000)>(), 2);
+/// ```
+#[macro_export]
+macro_rules! bounded_saturating {
+    ( $max:expr $(,)? ) => {
+        $crate::BoundedSaturating<$crate::small_unsigned!($max), { ($max) as usize }>
+    };
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::BoundedSaturating;
+    use core::mem::size_of;
+    use core::num::Saturating;
+    use static_assertions::assert_type_eq_all;
+
+    const MAX: usize = 200;
+
+    type Bounded = bounded_saturating!(MAX);
+
+    #[test]
+    fn bounded_macro() {
+        assert_type_eq_all!(Bounded, BoundedSaturating<u8, 200>);
+        assert_eq!(size_of::<Bounded>(), 1);
+        assert_eq!(size_of::<bounded_saturating!(70_000)>(), 4);
+    }
+
+    #[test]
+    fn bounded_add_clamps_to_max() {
+        let sum = Bounded::new(150) + Bounded::new(100);
+        assert_eq!(sum.usize(), MAX);
+        assert_eq!(sum.get(), 200_u8);
+
+        // Type could hold 255, but the logical bound wins
+        let sum = Bounded::new(MAX) + Bounded::new(MAX);
+        assert_eq!(sum.usize(), MAX);
+
+        let sum = Bounded::new(50) + Bounded::new(60);
+        assert_eq!(sum.usize(), 110);
+
+        // Construction clamps too
+        assert_eq!(Bounded::new(255).usize(), MAX);
+        assert_eq!(Bounded::new(usize::MAX).usize(), MAX);
+    }
+
+    #[test]
+    fn bounded_sub_clamps_to_zero() {
+        let diff = Bounded::new(10) - Bounded::new(20);
+        assert_eq!(diff.usize(), 0);
+
+        let diff = Bounded::new(MAX) - Bounded::new(1);
+        assert_eq!(diff.usize(), MAX - 1);
+
+        assert_eq!(Bounded::default().usize(), 0);
+    }
+
+    #[test]
+    fn bounded_saturating_conversion() {
+        let sat: Saturating<u8> = Bounded::new(MAX).into();
+        let sat = sat + Saturating(100);
+        assert_eq!(sat, Saturating(u8::MAX));
+
+        let bounded: Bounded = sat.into();
+        assert_eq!(bounded.usize(), MAX);
+
+        let bounded: Bounded = Saturating(42).into();
+        assert_eq!(bounded.usize(), 42);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn bounded_serde() {
+        let json = serde_json::to_string(&Bounded::new(150)).unwrap();
+        assert_eq!(json, "150");
+        assert_eq!(
+            serde_json::from_str::<Bounded>(&json).unwrap(),
+            Bounded::new(150)
+        );
+        assert_eq!(
+            serde_json::from_str::<Bounded>("200").unwrap(),
+            Bounded::new(MAX)
+        );
+
+        // Fits `u8`, but not the logical bound
+        assert!(serde_json::from_str::<Bounded>("201")
+            .unwrap_err()
+            .is_data());
+        assert!(serde_json::from_str::<Bounded>("256").is_err());
+    }
+}
@@ -0,0 +1,330 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+
+use crate::{SmallNumError, SmallUnsigned, SmallUnsignedLabel};
+
+// Encoding ------------------------------------------------------------------------------------------------------------
+
+/// Encode each `(label, value)` pair as a little-endian field of `label`'s width, back-to-back, into one buffer.
+/// No tags or lengths are written: the decoder must be given the same labels (see `decode_dynamic`).
+///
+/// # Panics
+///
+/// If a value exceeds the max of its label.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{decode_dynamic, encode_dynamic, SmallUnsignedLabel};
+///
+/// let fields = [
+///     (SmallUnsignedLabel::U8, 0x01),
+///     (SmallUnsignedLabel::U16, 0x0302),
+/// ];
+///
+/// let bytes = encode_dynamic(&fields);
+/// assert_eq!(&*bytes, &[0x01, 0x02, 0x03]);
+///
+/// let labels = [SmallUnsignedLabel::U8, SmallUnsignedLabel::U16];
+/// assert_eq!(decode_dynamic(&bytes, &labels), Some(vec![0x01, 0x0302]));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_dynamic(values: &[(SmallUnsignedLabel, usize)]) -> Box<[u8]> {
+    let len = values.iter().map(|(label, _)| label.byte_size()).sum();
+    let mut bytes = Vec::with_capacity(len);
+
+    for (label, value) in values {
+        assert!(
+            (*value as u128) <= label.max_value(),
+            "value exceeds max of label"
+        );
+
+        let le = (*value as u128).to_le_bytes();
+        bytes.extend_from_slice(&le[..label.byte_size()]);
+    }
+
+    bytes.into_boxed_slice()
+}
+
+// Decoding ------------------------------------------------------------------------------------------------------------
+
+/// Read a little-endian field, whose width is given by `label`, from `bytes` at `offset`.
+/// On success, `offset` is advanced past the field and the widened value is returned.
+/// Returns `None`, leaving `offset` unchanged, if `bytes` is too short or the value exceeds `usize::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{read_small, SmallUnsignedLabel};
+///
+/// // u8 = 0x01, u16 = 0x0302, u8 = 0x04
+/// let bytes: [u8; 4] = [0x01, 0x02, 0x03, 0x04];
+/// let mut offset = 0;
+///
+/// assert_eq!(read_small(&bytes, &mut offset, SmallUnsignedLabel::U8), Some(0x01));
+/// assert_eq!(read_small(&bytes, &mut offset, SmallUnsignedLabel::U16), Some(0x0302));
+/// assert_eq!(read_small(&bytes, &mut offset, SmallUnsignedLabel::U8), Some(0x04));
+/// assert_eq!(offset, bytes.len());
+/// ```
+pub fn read_small(bytes: &[u8], offset: &mut usize, label: SmallUnsignedLabel) -> Option<usize> {
+    let end = offset.checked_add(label.byte_size())?;
+    let field = bytes.get(*offset..end)?;
+    let value = field
+        .iter()
+        .rev()
+        .fold(0_u128, |acc, byte| (acc << 8) | (*byte as u128));
+
+    if value > (usize::MAX as u128) {
+        return None;
+    }
+
+    *offset = end;
+    Some(value as usize)
+}
+
+/// Read a `byte_width` big-endian field (e.g. a network header field) from the start of `bytes`,
+/// check it against the logical bound `max`, and narrow it into `T` (e.g. `small_unsigned!(MAX)`).
+/// Values above `max` are reported as `OutOfBound`, values above `T::MAX` as `Overflow`.
+/// Values too wide for `usize` are reported as `FieldTooWide`, with the field's width.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{read_be_small, small_unsigned, SmallNumError};
+///
+/// const MAX_PORT_IDX: usize = 1_000;
+///
+/// let port: small_unsigned!(MAX_PORT_IDX) = read_be_small(&[0x01, 0xf4], 2, MAX_PORT_IDX).unwrap();
+/// assert_eq!(port, 500);
+///
+/// let bad: Result<small_unsigned!(MAX_PORT_IDX), _> = read_be_small(&[0x03, 0xe9], 2, MAX_PORT_IDX);
+/// assert_eq!(bad, Err(SmallNumError::OutOfBound { value: 1_001, bound: MAX_PORT_IDX }));
+/// ```
+pub fn read_be_small<T: SmallUnsigned>(
+    bytes: &[u8],
+    byte_width: usize,
+    max: usize,
+) -> Result<T, SmallNumError> {
+    let field = bytes.get(..byte_width).ok_or(SmallNumError::Truncated {
+        needed: byte_width,
+        available: bytes.len(),
+    })?;
+
+    let value = field
+        .iter()
+        .try_fold(0_usize, |acc, byte| {
+            acc.checked_mul(1 << 8)?.checked_add(*byte as usize)
+        })
+        .ok_or(SmallNumError::FieldTooWide { byte_width })?;
+
+    if value > max {
+        return Err(SmallNumError::OutOfBound { value, bound: max });
+    }
+
+    if value > T::MAX {
+        return Err(SmallNumError::overflow(value, T::MAX));
+    }
+
+    Ok(T::checked_from(value))
+}
+
+/// Decode a buffer written by `encode_dynamic`, given the same labels in the same order.
+/// Returns `None` if `bytes` is too short, has trailing bytes, or holds a value that exceeds `usize::MAX`.
+#[cfg(feature = "alloc")]
+pub fn decode_dynamic(bytes: &[u8], labels: &[SmallUnsignedLabel]) -> Option<Vec<usize>> {
+    let mut offset = 0;
+    let values = labels
+        .iter()
+        .map(|label| read_small(bytes, &mut offset, *label))
+        .collect::<Option<Vec<usize>>>()?;
+
+    if offset != bytes.len() {
+        return None;
+    }
+
+    Some(values)
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{read_be_small, read_small, small_unsigned, SmallNumError, SmallUnsignedLabel};
+
+    #[cfg(feature = "alloc")]
+    use crate::{decode_dynamic, encode_dynamic};
+
+    #[cfg(feature = "alloc")]
+    use alloc::vec::Vec;
+
+    #[test]
+    fn read_mixed_width() {
+        let bytes: [u8; 15] = [
+            0xff, // u8
+            0x34, 0x12, // u16
+            0x78, 0x56, 0x34, 0x12, // u32
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // u64
+        ];
+        let mut offset = 0;
+
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U8),
+            Some(0xff)
+        );
+        assert_eq!(offset, 1);
+
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U16),
+            Some(0x1234)
+        );
+        assert_eq!(offset, 3);
+
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U32),
+            Some(0x1234_5678)
+        );
+        assert_eq!(offset, 7);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(
+                read_small(&bytes, &mut offset, SmallUnsignedLabel::USIZE),
+                Some(1)
+            );
+            assert_eq!(offset, 15);
+        }
+    }
+
+    #[test]
+    fn read_truncated() {
+        let bytes: [u8; 3] = [0x01, 0x02, 0x03];
+        let mut offset = 2;
+
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U16),
+            None
+        );
+        assert_eq!(offset, 2);
+
+        let mut offset = usize::MAX;
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U8),
+            None
+        );
+        assert_eq!(offset, usize::MAX);
+    }
+
+    #[test]
+    fn read_too_wide_for_host() {
+        let mut bytes = [0_u8; 16];
+        bytes[15] = 0x01;
+        let mut offset = 0;
+
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U128),
+            None
+        );
+        assert_eq!(offset, 0);
+
+        // Same width, but the value fits
+        bytes[15] = 0x00;
+        bytes[0] = 0x2a;
+        assert_eq!(
+            read_small(&bytes, &mut offset, SmallUnsignedLabel::U128),
+            Some(0x2a)
+        );
+        assert_eq!(offset, 16);
+    }
+
+    #[test]
+    fn read_be() {
+        const MAX: usize = 1_000;
+        type Field = small_unsigned!(MAX);
+
+        let val: Field = read_be_small(&[0x03, 0xe8, 0xff], 2, MAX).unwrap();
+        assert_eq!(val, 1_000_u16);
+
+        let val: Field = read_be_small(&[0x2a], 1, MAX).unwrap();
+        assert_eq!(val, 42);
+
+        let val: Field = read_be_small(&[0x00, 0x00, 0x00, 0x07], 4, MAX).unwrap();
+        assert_eq!(val, 7);
+
+        assert_eq!(
+            read_be_small::<Field>(&[0x03, 0xe9], 2, MAX),
+            Err(SmallNumError::OutOfBound {
+                value: 1_001,
+                bound: MAX
+            })
+        );
+
+        assert_eq!(
+            read_be_small::<Field>(&[0x03], 2, MAX),
+            Err(SmallNumError::Truncated {
+                needed: 2,
+                available: 1
+            })
+        );
+
+        // Logical bound exceeds storage
+        assert_eq!(
+            read_be_small::<u8>(&[0x01, 0x00], 2, MAX),
+            Err(SmallNumError::Overflow {
+                value: 256,
+                max: 255,
+                label: SmallUnsignedLabel::U8
+            })
+        );
+
+        // Too wide for `usize`
+        assert_eq!(
+            read_be_small::<Field>(&[0xff; 17], 17, MAX),
+            Err(SmallNumError::FieldTooWide { byte_width: 17 })
+        );
+
+        // Wide field, narrow value
+        let mut wide = [0_u8; 17];
+        wide[16] = 0x07;
+        let val: Field = read_be_small(&wide, 17, MAX).unwrap();
+        assert_eq!(val, 7);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn dynamic_round_trip() {
+        let fields = [
+            (SmallUnsignedLabel::U16, 0xbeef),
+            (SmallUnsignedLabel::U8, 0),
+            (SmallUnsignedLabel::U32, 0x1234_5678),
+            (SmallUnsignedLabel::U8, 255),
+            (SmallUnsignedLabel::USIZE, usize::MAX),
+            (SmallUnsignedLabel::U128, 42),
+        ];
+        let labels: Vec<SmallUnsignedLabel> = fields.iter().map(|(label, _)| *label).collect();
+        let values: Vec<usize> = fields.iter().map(|(_, value)| *value).collect();
+
+        let bytes = encode_dynamic(&fields);
+        assert_eq!(
+            bytes.len(),
+            2 + 1 + 4 + 1 + core::mem::size_of::<usize>() + 16
+        );
+        assert_eq!(&bytes[..4], &[0xef, 0xbe, 0x00, 0x78]);
+        assert_eq!(decode_dynamic(&bytes, &labels), Some(values));
+
+        // Empty
+        assert!(encode_dynamic(&[]).is_empty());
+        assert_eq!(decode_dynamic(&[], &[]), Some(Vec::new()));
+
+        // Truncated and trailing
+        assert_eq!(decode_dynamic(&bytes[..bytes.len() - 1], &labels), None);
+        assert_eq!(decode_dynamic(&bytes, &labels[..labels.len() - 1]), None);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn encode_dynamic_overflow() {
+        encode_dynamic(&[(SmallUnsignedLabel::U8, 256)]);
+    }
+}
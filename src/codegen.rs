@@ -0,0 +1,75 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::SmallUnsignedLabel;
+
+// Source Generation ---------------------------------------------------------------------------------------------------
+
+/// Emit Rust source for a packed struct named `name`, with one public field per `(field_name, label)` pair.
+/// For build scripts and schema-to-code generators that can't (or prefer not to) use the macros.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{emit_struct, SmallUnsignedLabel};
+///
+/// let src = emit_struct(
+///     "Header",
+///     &[
+///         ("kind", SmallUnsignedLabel::new(10)),
+///         ("len", SmallUnsignedLabel::new(1_500)),
+///     ],
+/// );
+///
+/// assert_eq!(
+///     src,
+///     "#[repr(C, packed)]\npub struct Header {\n    pub kind: u8,\n    pub len: u16,\n}\n"
+/// );
+/// ```
+pub fn emit_struct(name: &str, fields: &[(&str, SmallUnsignedLabel)]) -> String {
+    let mut src = String::new();
+
+    // Writing to a `String` can't fail
+    let _ = writeln!(src, "#[repr(C, packed)]");
+    let _ = writeln!(src, "pub struct {} {{", name);
+    for (field, label) in fields {
+        let _ = writeln!(src, "    pub {}: {},", field, label.type_name());
+    }
+    let _ = writeln!(src, "}}");
+
+    src
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{emit_struct, SmallUnsignedLabel};
+
+    #[test]
+    fn emit_field_types() {
+        let src = emit_struct(
+            "Record",
+            &[
+                ("id", SmallUnsignedLabel::new(70_000)),
+                ("flags", SmallUnsignedLabel::U8),
+                ("offset", SmallUnsignedLabel::USIZE),
+            ],
+        );
+
+        assert!(src.starts_with("#[repr(C, packed)]\npub struct Record {\n"));
+        assert!(src.contains("    pub id: u32,\n"));
+        assert!(src.contains("    pub flags: u8,\n"));
+        assert!(src.contains("    pub offset: usize,\n"));
+        assert!(src.ends_with("}\n"));
+    }
+
+    #[test]
+    fn emit_empty() {
+        assert_eq!(
+            emit_struct("Unit", &[]),
+            "#[repr(C, packed)]\npub struct Unit {\n}\n"
+        );
+    }
+}
@@ -0,0 +1,64 @@
+use ::arbitrary::{Result, Unstructured};
+
+// Fuzzing Input -------------------------------------------------------------------------------------------------------
+
+/// Draw a value in `0..=max` from fuzzer input, i.e. one that always fits `small_unsigned!(max)`
+/// (and narrows with `checked_from` without panicking). Yields `0` once input is exhausted.
+///
+/// # Example
+///
+/// ```
+/// use arbitrary::Unstructured;
+/// use smallnum::{arbitrary_small_unsigned, small_unsigned, SmallUnsigned};
+///
+/// const MAX_LEN: usize = 500;
+///
+/// let mut u = Unstructured::new(&[0xff, 0xff, 0x07, 0x00]);
+/// let len = arbitrary_small_unsigned(&mut u, MAX_LEN).unwrap();
+///
+/// assert!(len <= MAX_LEN);
+/// let _small_len = <small_unsigned!(MAX_LEN)>::checked_from(len);
+/// ```
+pub fn arbitrary_small_unsigned(u: &mut Unstructured, max: usize) -> Result<usize> {
+    u.int_in_range(0..=max)
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::{arbitrary_small_unsigned, SmallSignedLabel, SmallUnsignedLabel};
+    use ::arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary_in_range() {
+        let data: [u8; 64] = core::array::from_fn(|i| (i as u8).wrapping_mul(37));
+        let mut u = Unstructured::new(&data);
+
+        for max in [0, 1, 200, 255, 256, 1_000, usize::MAX] {
+            for _ in 0..4 {
+                assert!(arbitrary_small_unsigned(&mut u, max).unwrap() <= max);
+            }
+        }
+
+        // Exhausted input
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(arbitrary_small_unsigned(&mut u, 1_000), Ok(0));
+    }
+
+    #[test]
+    fn arbitrary_labels() {
+        let data: [u8; 512] = core::array::from_fn(|i| ((i * 7_919) % 251) as u8);
+        let mut u = Unstructured::new(&data);
+
+        // Every variant is reachable
+        let (mut unsigned_seen, mut signed_seen) = ([false; 6], [false; 6]);
+        for _ in 0..64 {
+            unsigned_seen[SmallUnsignedLabel::arbitrary(&mut u).unwrap() as usize] = true;
+            signed_seen[SmallSignedLabel::arbitrary(&mut u).unwrap() as usize] = true;
+        }
+
+        assert!(unsigned_seen.iter().all(|seen| *seen));
+        assert!(signed_seen.iter().all(|seen| *seen));
+    }
+}
@@ -0,0 +1,62 @@
+use ::bytemuck::Pod;
+
+use crate::SmallUnsigned;
+
+// Zero-copy Byte Views ------------------------------------------------------------------------------------------------
+
+/// Small unsigned types that can be safely viewed as raw bytes, and back.
+/// Every primitive `small_unsigned!` selects qualifies, so this is the bound to use instead of naming `bytemuck::Pod`.
+pub trait SmallPod: SmallUnsigned + Pod {}
+
+impl<T: SmallUnsigned + Pod> SmallPod for T {}
+
+/// View a slice of small values as its underlying bytes, in native endianness (e.g. for memory-mapped tables).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{as_bytes, small_unsigned};
+///
+/// let idxs: [small_unsigned!(1_000); 2] = [1, 2];
+///
+/// assert_eq!(as_bytes(&idxs).len(), 4);
+/// ```
+pub fn as_bytes<T: SmallPod>(slice: &[T]) -> &[u8] {
+    ::bytemuck::cast_slice(slice)
+}
+
+/// View bytes as a slice of small values, the inverse of `as_bytes`.
+/// Returns `None` if `bytes` isn't aligned for `T` or its length isn't a multiple of `T`'s size.
+pub fn try_from_bytes<T: SmallPod>(bytes: &[u8]) -> Option<&[T]> {
+    ::bytemuck::try_cast_slice(bytes).ok()
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::{as_bytes, small_unsigned, try_from_bytes};
+
+    #[test]
+    fn bytes_round_trip() {
+        type Idx = small_unsigned!(1_000);
+
+        let idxs: [Idx; 4] = [0, 1, 999, 1_000];
+        let bytes = as_bytes(&idxs);
+
+        assert_eq!(bytes.len(), 8);
+        assert_eq!(&bytes[4..6], &999_u16.to_ne_bytes());
+        assert_eq!(try_from_bytes::<Idx>(bytes), Some(&idxs[..]));
+
+        // Odd length
+        assert_eq!(try_from_bytes::<Idx>(&bytes[..7]), None);
+
+        // Misaligned
+        assert_eq!(try_from_bytes::<Idx>(&bytes[1..7]), None);
+
+        // Single bytes never misalign
+        let flags: [small_unsigned!(200); 3] = [1, 2, 3];
+        assert_eq!(as_bytes(&flags), &[1, 2, 3]);
+        assert_eq!(try_from_bytes::<u8>(&[1, 2, 3]), Some(&flags[..]));
+    }
+}
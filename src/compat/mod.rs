@@ -0,0 +1,11 @@
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
+#[cfg(feature = "bytemuck")]
+pub mod bytemuck;
+
+#[cfg(feature = "num-traits")]
+pub mod num_traits;
+
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec;
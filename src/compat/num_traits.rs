@@ -0,0 +1,80 @@
+use ::num_traits::{Bounded, One, Zero};
+
+use crate::SmallUnsigned;
+
+// Generic Constants ---------------------------------------------------------------------------------------------------
+
+// Every type `small_unsigned!`/`small_signed!` select is a primitive integer, so `num_traits::PrimInt` (and everything
+// it implies, e.g. `Zero`, `One`, `Bounded`, `NumCast`) composes with `SmallUnsigned`/`SmallSigned` as a plain extra
+// bound. `NonZero*` types from `small_nonzero_unsigned!` are the exception: they implement none of these.
+
+/// Zero of a small unsigned type, for generic code bounded by `num_traits`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{small_unsigned, small_zero};
+///
+/// let idx: small_unsigned!(1_000) = small_zero();
+///
+/// assert_eq!(idx, 0);
+/// ```
+pub fn small_zero<T: SmallUnsigned + Zero>() -> T {
+    T::zero()
+}
+
+/// One of a small unsigned type, for generic code bounded by `num_traits`.
+pub fn small_one<T: SmallUnsigned + One>() -> T {
+    T::one()
+}
+
+/// Max of a small unsigned type's underlying primitive (not the bound passed to the macro),
+/// for generic code bounded by `num_traits`.
+pub fn small_max<T: SmallUnsigned + Bounded>() -> T {
+    T::max_value()
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        small_max, small_one, small_signed, small_unsigned, small_zero, SmallSigned, SmallUnsigned,
+    };
+    use ::num_traits::PrimInt;
+
+    // Generic over `num_traits`, agnostic of `smallnum`
+    fn count_ones_sum<T: PrimInt>(vals: &[T]) -> u32 {
+        vals.iter().map(|v| v.count_ones()).sum()
+    }
+
+    #[test]
+    fn num_traits_helpers() {
+        type Idx = small_unsigned!(1_000);
+
+        assert_eq!(small_zero::<Idx>(), 0);
+        assert_eq!(small_one::<Idx>(), 1);
+        assert_eq!(small_max::<Idx>(), u16::MAX);
+        assert_eq!(small_max::<Idx>().usize(), <Idx as SmallUnsigned>::MAX);
+    }
+
+    #[test]
+    fn num_traits_bounds_compose() {
+        fn total<T: SmallUnsigned + PrimInt>(vals: &[T]) -> Option<T> {
+            vals.iter().try_fold(T::zero(), |acc, v| acc.checked_add(v))
+        }
+
+        let idxs: [small_unsigned!(200); 3] = [100, 100, 55];
+        assert_eq!(total(&idxs), Some(255));
+        assert_eq!(total(&[100_u8, 100, 56]), None);
+        assert_eq!(count_ones_sum(&idxs), 3 + 3 + 5);
+
+        fn min_isize<T: SmallSigned + PrimInt>(vals: &[T]) -> isize {
+            vals.iter().copied().fold(T::max_value(), T::min).isize()
+        }
+
+        let deltas: [small_signed!(-200); 3] = [-200, 5, 100];
+        assert_eq!(min_isize(&deltas), -200);
+        assert_eq!(count_ones_sum(&deltas), 11 + 2 + 3);
+    }
+}
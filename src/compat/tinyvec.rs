@@ -0,0 +1,75 @@
+use ::tinyvec::{Array, ArrayVec};
+
+use crate::{SmallNumError, SmallUnsigned};
+
+// Stack-allocated Buffers ---------------------------------------------------------------------------------------------
+
+/// Narrow `value` into the item type of `vec` (via `checked_from`) and push it.
+/// Errors, leaving `vec` untouched, if `value` exceeds max for the item type or if `vec` is full.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{push_small, small_unsigned, SmallNumError, SmallUnsignedLabel};
+/// use tinyvec::ArrayVec;
+///
+/// let mut idxs: ArrayVec<[small_unsigned!(200); 2]> = ArrayVec::new();
+///
+/// assert_eq!(push_small(&mut idxs, 42), Ok(()));
+/// assert_eq!(
+///     push_small(&mut idxs, 300),
+///     Err(SmallNumError::Overflow { value: 300, max: 255, label: SmallUnsignedLabel::U8 })
+/// );
+/// assert_eq!(idxs.as_slice(), &[42]);
+/// ```
+pub fn push_small<A: Array>(vec: &mut ArrayVec<A>, value: usize) -> Result<(), SmallNumError>
+where
+    A::Item: SmallUnsigned,
+{
+    let max = A::Item::MAX;
+    if value > max {
+        return Err(SmallNumError::overflow(value, max));
+    }
+
+    if vec.len() == vec.capacity() {
+        return Err(SmallNumError::CapacityExceeded {
+            capacity: vec.capacity(),
+        });
+    }
+
+    vec.push(A::Item::checked_from(value));
+    Ok(())
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{push_small, small_unsigned, SmallNumError, SmallUnsignedLabel};
+    use tinyvec::ArrayVec;
+
+    #[test]
+    fn tinyvec_push_small() {
+        let mut idxs: ArrayVec<[small_unsigned!(500); 3]> = ArrayVec::new();
+
+        assert_eq!(push_small(&mut idxs, 0), Ok(()));
+        assert_eq!(push_small(&mut idxs, 500), Ok(()));
+        assert_eq!(
+            push_small(&mut idxs, 70_000),
+            Err(SmallNumError::Overflow {
+                value: 70_000,
+                max: u16::MAX as usize,
+                label: SmallUnsignedLabel::U16
+            })
+        );
+        assert_eq!(push_small(&mut idxs, u16::MAX as usize), Ok(()));
+        assert_eq!(idxs.as_slice(), &[0, 500, u16::MAX]);
+
+        assert_eq!(
+            push_small(&mut idxs, 1),
+            Err(SmallNumError::CapacityExceeded { capacity: 3 })
+        );
+        assert_eq!(idxs.len(), 3);
+    }
+}
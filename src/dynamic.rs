@@ -0,0 +1,294 @@
+use core::ops::{Add, Mul};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+
+#[cfg(feature = "alloc")]
+use crate::SmallUnsigned;
+
+use crate::SmallUnsignedLabel;
+
+// Dynamic Dispatch ----------------------------------------------------------------------------------------------------
+
+/// Object-safe view of a small unsigned value, for when the underlying type is only known at runtime.
+pub trait DynSmall {
+    /// **Upcast:** Get value of small unsigned as host register-width unsigned (e.g. `usize`)
+    fn widen(&self) -> usize;
+}
+
+impl<T: crate::SmallUnsigned> DynSmall for T {
+    fn widen(&self) -> usize {
+        self.usize()
+    }
+}
+
+// Runtime Type Mapping ------------------------------------------------------------------------------------------------
+
+/// Sum a slice, boxing the total in the smallest unsigned type that can represent it.
+/// Unlike the macros, the type is selected at runtime, from the actual total.
+/// Panics if the total exceeds `usize::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::smart_sum;
+/// use core::mem::size_of_val;
+///
+/// let vals: [u8; 3] = [200, 200, 200];
+/// let total = smart_sum(&vals);
+///
+/// assert_eq!(total.widen(), 600);
+/// assert_eq!(size_of_val(&*total), 2);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn smart_sum(slice: &[impl SmallUnsigned]) -> Box<dyn DynSmall> {
+    let total = slice
+        .iter()
+        .try_fold(0_usize, |acc, num| acc.checked_add(num.usize()))
+        .expect("sum exceeds usize::MAX");
+
+    match SmallUnsignedLabel::new(total) {
+        SmallUnsignedLabel::U8 => Box::new(u8::checked_from(total)),
+
+        #[cfg(any(
+            target_pointer_width = "16",
+            target_pointer_width = "32",
+            target_pointer_width = "64",
+            target_pointer_width = "128",
+        ))]
+        SmallUnsignedLabel::U16 => Box::new(u16::checked_from(total)),
+
+        #[cfg(any(
+            target_pointer_width = "32",
+            target_pointer_width = "64",
+            target_pointer_width = "128",
+        ))]
+        SmallUnsignedLabel::U32 => Box::new(u32::checked_from(total)),
+
+        #[cfg(any(target_pointer_width = "64", target_pointer_width = "128",))]
+        SmallUnsignedLabel::U64 => Box::new(u64::checked_from(total)),
+
+        // Label never wider than host for a `usize` input
+        _ => Box::new(total),
+    }
+}
+
+// Runtime-selected Integer -------------------------------------------------------------------------------------------
+
+/// Unsigned integer whose width is selected at runtime, for interpreters and dynamic schemas.
+/// Arithmetic promotes to a wider variant instead of overflowing.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SmallInt {
+    /// A `u8` value.
+    U8(u8),
+
+    /// A `u16` value.
+    U16(u16),
+
+    /// A `u32` value.
+    U32(u32),
+
+    /// A `u64` value.
+    U64(u64),
+
+    /// A `u128` value.
+    U128(u128),
+}
+
+impl SmallInt {
+    /// Store `value` in the variant for the smallest type capable of representing `max`
+    /// (e.g. `from_usize(500, 7)` -> `SmallInt::U16(7)`).
+    /// Panics if `value` exceeds `max`.
+    pub fn from_usize(max: usize, value: usize) -> Self {
+        assert!(value <= max);
+        SmallInt::with_label(SmallUnsignedLabel::new(max), value as u128)
+    }
+
+    /// Label for the variant's type (never `SmallUnsignedLabel::USIZE`).
+    pub fn label(&self) -> SmallUnsignedLabel {
+        match self {
+            SmallInt::U8(_) => SmallUnsignedLabel::U8,
+            SmallInt::U16(_) => SmallUnsignedLabel::U16,
+            SmallInt::U32(_) => SmallUnsignedLabel::U32,
+            SmallInt::U64(_) => SmallUnsignedLabel::U64,
+            SmallInt::U128(_) => SmallUnsignedLabel::U128,
+        }
+    }
+
+    /// **Upcast:** Get value as host register-width unsigned (e.g. `usize`).
+    /// Panics if value exceeds `usize::MAX`, only possible for variants wider than the host.
+    pub fn usize(&self) -> usize {
+        let value = self.u128();
+        assert!(value <= (usize::MAX as u128));
+        value as usize
+    }
+
+    /// Get value as `u128`, lossless for every variant.
+    pub fn u128(&self) -> u128 {
+        match *self {
+            SmallInt::U8(v) => v as u128,
+            SmallInt::U16(v) => v as u128,
+            SmallInt::U32(v) => v as u128,
+            SmallInt::U64(v) => v as u128,
+            SmallInt::U128(v) => v,
+        }
+    }
+
+    // Store `value` in the variant for `label`, or a wider one if it doesn't fit.
+    fn with_label(label: SmallUnsignedLabel, value: u128) -> Self {
+        let fitting = [
+            SmallUnsignedLabel::U8,
+            SmallUnsignedLabel::U16,
+            SmallUnsignedLabel::U32,
+            SmallUnsignedLabel::U64,
+        ]
+        .iter()
+        .copied()
+        .find(|l| value <= l.max_value())
+        .unwrap_or(SmallUnsignedLabel::U128);
+
+        match label.max(fitting) {
+            SmallUnsignedLabel::U8 => SmallInt::U8(value as u8),
+            SmallUnsignedLabel::U16 => SmallInt::U16(value as u16),
+            SmallUnsignedLabel::U32 => SmallInt::U32(value as u32),
+            SmallUnsignedLabel::U64 => SmallInt::U64(value as u64),
+            SmallUnsignedLabel::U128 => SmallInt::U128(value),
+            SmallUnsignedLabel::USIZE => {
+                SmallInt::with_label(SmallUnsignedLabel::new(usize::MAX), value)
+            }
+        }
+    }
+}
+
+/// Result uses the wider operand's variant, promoted further if the sum doesn't fit.
+/// Panics if the sum exceeds `u128::MAX`.
+impl Add for SmallInt {
+    type Output = SmallInt;
+
+    fn add(self, rhs: SmallInt) -> SmallInt {
+        let value = self.u128().checked_add(rhs.u128()).expect("overflow");
+        SmallInt::with_label(self.label().max(rhs.label()), value)
+    }
+}
+
+/// Result uses the wider operand's variant, promoted further if the product doesn't fit.
+/// Panics if the product exceeds `u128::MAX`.
+impl Mul for SmallInt {
+    type Output = SmallInt;
+
+    fn mul(self, rhs: SmallInt) -> SmallInt {
+        let value = self.u128().checked_mul(rhs.u128()).expect("overflow");
+        SmallInt::with_label(self.label().max(rhs.label()), value)
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{DynSmall, SmallInt, SmallUnsignedLabel};
+
+    #[test]
+    fn dyn_small() {
+        let vals: [&dyn DynSmall; 3] = [&5_u8, &500_u16, &5_usize];
+        let widened: [usize; 3] = [vals[0].widen(), vals[1].widen(), vals[2].widen()];
+
+        assert_eq!(widened, [5, 500, 5]);
+    }
+
+    #[test]
+    fn small_int_construct() {
+        assert_eq!(SmallInt::from_usize(200, 7), SmallInt::U8(7));
+        assert_eq!(SmallInt::from_usize(500, 7), SmallInt::U16(7));
+        assert_eq!(SmallInt::from_usize(100_000, 7), SmallInt::U32(7));
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(SmallInt::from_usize(usize::MAX, 7), SmallInt::U64(7));
+
+        assert_eq!(
+            SmallInt::from_usize(500, 7).label(),
+            SmallUnsignedLabel::U16
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn small_int_construct_over_max() {
+        SmallInt::from_usize(200, 201);
+    }
+
+    #[test]
+    fn small_int_widen() {
+        assert_eq!(SmallInt::U8(200).usize(), 200);
+        assert_eq!(SmallInt::U16(500).usize(), 500);
+        assert_eq!(SmallInt::U128(500).usize(), 500);
+        assert_eq!(SmallInt::U128(u128::MAX).u128(), u128::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn small_int_widen_overflow() {
+        SmallInt::U128(u128::MAX).usize();
+    }
+
+    #[test]
+    fn small_int_promote() {
+        // No overflow, keeps the wider operand's width
+        assert_eq!(SmallInt::U8(100) + SmallInt::U8(100), SmallInt::U8(200));
+        assert_eq!(SmallInt::U8(1) + SmallInt::U16(1), SmallInt::U16(2));
+        assert_eq!(SmallInt::U8(2) * SmallInt::U32(3), SmallInt::U32(6));
+
+        // Overflow, promotes
+        assert_eq!(SmallInt::U8(200) + SmallInt::U8(100), SmallInt::U16(300));
+        assert_eq!(SmallInt::U8(255) * SmallInt::U8(255), SmallInt::U16(65_025));
+        assert_eq!(
+            SmallInt::U16(u16::MAX) * SmallInt::U16(u16::MAX),
+            SmallInt::U32(4_294_836_225)
+        );
+        assert_eq!(
+            SmallInt::U64(u64::MAX) + SmallInt::U8(1),
+            SmallInt::U128(u64::MAX as u128 + 1)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn small_int_promote_overflow() {
+        let _ = SmallInt::U128(u128::MAX) + SmallInt::U8(1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn smart_sum() {
+        use crate::smart_sum;
+        use core::mem::size_of_val;
+
+        let small: [u8; 4] = [1, 2, 3, 4];
+        let total = smart_sum(&small);
+        assert_eq!(total.widen(), 10);
+        assert_eq!(size_of_val(&*total), 1);
+
+        let medium: [u8; 4] = [255, 255, 255, 255];
+        let total = smart_sum(&medium);
+        assert_eq!(total.widen(), 1_020);
+        assert_eq!(size_of_val(&*total), 2);
+
+        let wide: [u16; 3] = [u16::MAX, u16::MAX, 2];
+        let total = smart_sum(&wide);
+        assert_eq!(total.widen(), 131_072);
+        assert_eq!(size_of_val(&*total), 4);
+
+        let empty: [u32; 0] = [];
+        let total = smart_sum(&empty);
+        assert_eq!(total.widen(), 0);
+        assert_eq!(size_of_val(&*total), 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn smart_sum_overflow() {
+        crate::smart_sum(&[usize::MAX, 1]);
+    }
+}
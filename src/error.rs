@@ -0,0 +1,140 @@
+use core::fmt;
+
+use crate::{SmallSignedLabel, SmallUnsignedLabel};
+
+/// Errors returned by the non-panicking conversion APIs.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum SmallNumError {
+    /// Source and destination lengths differ.
+    LengthMismatch {
+        /// Length of the source.
+        src_len: usize,
+
+        /// Length of the destination.
+        dst_len: usize,
+    },
+
+    /// Value exceeds the max of the target primitive.
+    Overflow {
+        /// The value that didn't fit.
+        value: usize,
+
+        /// Max of the target primitive.
+        max: usize,
+
+        /// Label of the target primitive.
+        label: SmallUnsignedLabel,
+    },
+
+    /// Value exceeds a caller-supplied logical bound, tighter than the max of the target primitive.
+    OutOfBound {
+        /// The value that didn't fit.
+        value: usize,
+
+        /// The logical bound.
+        bound: usize,
+    },
+
+    /// Signed value is below the min of the target primitive.
+    SignedUnderflow {
+        /// The value that didn't fit.
+        value: isize,
+
+        /// Label of the target primitive.
+        label: SmallSignedLabel,
+    },
+
+    /// Signed value exceeds the max of the target primitive.
+    SignedOverflow {
+        /// The value that didn't fit.
+        value: isize,
+
+        /// Label of the target primitive.
+        label: SmallSignedLabel,
+    },
+
+    /// Zero passed where a non-zero value is required.
+    Zero,
+
+    /// Destination is already at capacity.
+    CapacityExceeded {
+        /// Capacity of the destination, in the unit it's written in (elements for `push_small`, bits for `BitWriter`).
+        capacity: usize,
+    },
+
+    /// Input ended before a complete field could be read.
+    Truncated {
+        /// Bytes the field requires.
+        needed: usize,
+
+        /// Bytes available.
+        available: usize,
+    },
+
+    /// Field holds a value too wide for `usize` on this host.
+    FieldTooWide {
+        /// Width of the field, in bytes.
+        byte_width: usize,
+    },
+}
+
+impl SmallNumError {
+    // Unsigned overflow of the primitive with max `max`, labeled to match the signed variants.
+    pub(crate) const fn overflow(value: usize, max: usize) -> Self {
+        SmallNumError::Overflow {
+            value,
+            max,
+            label: SmallUnsignedLabel::new(max),
+        }
+    }
+}
+
+impl fmt::Display for SmallNumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SmallNumError::LengthMismatch { src_len, dst_len } => write!(
+                f,
+                "length mismatch: source has {} elements, destination has {}",
+                src_len, dst_len
+            ),
+            SmallNumError::Overflow { value, max, label } => write!(
+                f,
+                "overflow: {} exceeds max {} of {}",
+                value,
+                max,
+                label.type_name()
+            ),
+            SmallNumError::OutOfBound { value, bound } => {
+                write!(f, "out of bound: {} exceeds bound {}", value, bound)
+            }
+            SmallNumError::SignedUnderflow { value, label } => write!(
+                f,
+                "underflow: {} is below min of {}",
+                value,
+                label.type_name()
+            ),
+            SmallNumError::SignedOverflow { value, label } => write!(
+                f,
+                "overflow: {} exceeds max of {}",
+                value,
+                label.type_name()
+            ),
+            SmallNumError::Zero => write!(f, "zero: value must be non-zero"),
+            SmallNumError::CapacityExceeded { capacity } => {
+                write!(
+                    f,
+                    "capacity exceeded: destination holds at most {}",
+                    capacity
+                )
+            }
+            SmallNumError::Truncated { needed, available } => write!(
+                f,
+                "truncated: field needs {} bytes, {} available",
+                needed, available
+            ),
+            SmallNumError::FieldTooWide { byte_width } => {
+                write!(f, "field too wide: {}-byte value exceeds usize", byte_width)
+            }
+        }
+    }
+}
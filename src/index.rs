@@ -0,0 +1,175 @@
+use core::ops;
+
+use crate::SmallUnsigned;
+
+// Index Newtype -------------------------------------------------------------------------------------------------------
+
+/// A widened small value, usable only as an index.
+/// Returned by [`SmallUnsigned::as_index`](crate::SmallUnsigned::as_index), can't be constructed from a raw `usize`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{small_unsigned, SmallUnsigned};
+///
+/// let items = ['a', 'b', 'c'];
+/// let idx: small_unsigned!(3) = 2;
+///
+/// assert_eq!(idx.as_index().get(&items), Some(&'c'));
+/// assert_eq!(items[idx.as_index()], 'c');
+/// ```
+///
+/// A raw `usize` can't stand in for an `Index`:
+///
+/// ```compile_fail
+/// use smallnum::Index;
+///
+/// let items = ['a', 'b', 'c'];
+/// let idx = Index(2);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub struct Index(usize);
+
+impl Index {
+    // Only reachable via `SmallUnsigned::as_index`.
+    pub(crate) const fn new(idx: usize) -> Self {
+        Index(idx)
+    }
+
+    /// Reference to the element of `slice` at this index, or `None` if out of bounds.
+    pub fn get<T>(self, slice: &[T]) -> Option<&T> {
+        slice.get(self.0)
+    }
+
+    /// Mutable reference to the element of `slice` at this index, or `None` if out of bounds.
+    pub fn get_mut<T>(self, slice: &mut [T]) -> Option<&mut T> {
+        slice.get_mut(self.0)
+    }
+}
+
+impl<T> ops::Index<Index> for [T] {
+    type Output = T;
+
+    fn index(&self, idx: Index) -> &T {
+        &self[idx.0]
+    }
+}
+
+impl<T> ops::IndexMut<Index> for [T] {
+    fn index_mut(&mut self, idx: Index) -> &mut T {
+        &mut self[idx.0]
+    }
+}
+
+// Unique Index --------------------------------------------------------------------------------------------------------
+
+/// A small index that can't be duplicated, only moved (e.g. to enforce single use of a free list slot).
+/// Deliberately not `Copy` or `Clone`, `into_usize` consumes it.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{small_unsigned, UniqueIndex};
+///
+/// let idx: UniqueIndex<small_unsigned!(100)> = UniqueIndex::new(42);
+/// assert_eq!(idx.into_usize(), 42);
+/// ```
+///
+/// Once consumed, it's gone:
+///
+/// ```compile_fail
+/// use smallnum::{small_unsigned, UniqueIndex};
+///
+/// let idx: UniqueIndex<small_unsigned!(100)> = UniqueIndex::new(42);
+/// let first = idx.into_usize();
+/// let second = idx.into_usize();
+/// ```
+///
+/// With the `serde` feature, serializes as the underlying primitive (e.g. `42`, not `[42]`).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(transparent)
+)]
+pub struct UniqueIndex<T: SmallUnsigned>(T);
+
+impl<T: SmallUnsigned> UniqueIndex<T> {
+    /// **Downcast:** Convert input `usize` (via `checked_from`) into a unique index.
+    /// Panics if `usize` exceeds max for the underlying unsigned primitive.
+    pub fn new(idx: usize) -> Self {
+        UniqueIndex(T::checked_from(idx))
+    }
+
+    /// **Upcast:** Consume the index, returning its value as `usize`.
+    pub fn into_usize(self) -> usize {
+        self.0.usize()
+    }
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{small_unsigned, SmallUnsigned, UniqueIndex};
+    use core::mem::size_of;
+
+    #[test]
+    fn index_get() {
+        let mut items = [10_u32, 20, 30];
+        let idx: small_unsigned!(3) = 1;
+
+        assert_eq!(idx.as_index().get(&items), Some(&20));
+        assert_eq!(items[idx.as_index()], 20);
+
+        *idx.as_index().get_mut(&mut items).unwrap() += 1;
+        items[idx.as_index()] += 1;
+        assert_eq!(items, [10, 22, 30]);
+
+        let past_end: small_unsigned!(3) = 3;
+        assert_eq!(past_end.as_index().get(&items), None);
+        assert_eq!(past_end.as_index().get_mut(&mut items), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_bounds() {
+        let items = [10_u32, 20, 30];
+        let past_end: small_unsigned!(3) = 3;
+        let _ = items[past_end.as_index()];
+    }
+
+    #[test]
+    fn unique_index_consume() {
+        let mut free_list: [Option<UniqueIndex<small_unsigned!(100)>>; 2] =
+            [Some(UniqueIndex::new(7)), Some(UniqueIndex::new(100))];
+
+        // Taking moves the index out, the slot can't hand it out twice
+        let idx = free_list[0].take().unwrap();
+        assert!(free_list[0].is_none());
+        assert_eq!(idx.into_usize(), 7);
+
+        assert_eq!(free_list[1].take().map(UniqueIndex::into_usize), Some(100));
+        assert_eq!(size_of::<UniqueIndex<small_unsigned!(100)>>(), 1);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unique_index_serde() {
+        type Idx = UniqueIndex<small_unsigned!(1_000)>;
+
+        let json = serde_json::to_string(&Idx::new(999)).unwrap();
+        assert_eq!(json, "999");
+        assert_eq!(serde_json::from_str::<Idx>(&json).unwrap(), Idx::new(999));
+
+        // Range of the underlying primitive still applies
+        assert!(serde_json::from_str::<Idx>("70000").is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unique_index_overflow() {
+        let _ = UniqueIndex::<small_unsigned!(100)>::new(256);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::SmallUnsignedLabel;
+
+// Field Layout --------------------------------------------------------------------------------------------------------
+
+/// Byte offsets of `N` small fields laid out back-to-back in a buffer, in declaration order.
+/// Fields are either aligned to their native alignment (like a `#[repr(C)]` struct) or packed with no padding.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{FieldLayout, SmallUnsignedLabel};
+///
+/// let labels = [
+///     SmallUnsignedLabel::U8,
+///     SmallUnsignedLabel::U32,
+///     SmallUnsignedLabel::U16,
+/// ];
+///
+/// let aligned = FieldLayout::aligned(labels);
+/// assert_eq!(aligned.offsets(), [0, 4, 8]);
+/// assert_eq!(aligned.size(), 12);
+///
+/// let packed = FieldLayout::packed(labels);
+/// assert_eq!(packed.offsets(), [0, 1, 5]);
+/// assert_eq!(packed.size(), 7);
+/// ```
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub struct FieldLayout<const N: usize> {
+    labels: [SmallUnsignedLabel; N],
+    offsets: [usize; N],
+    size: usize,
+}
+
+impl<const N: usize> FieldLayout<N> {
+    /// Lay out fields at their native alignment, padding the total size to a multiple of the largest alignment.
+    pub const fn aligned(labels: [SmallUnsignedLabel; N]) -> Self {
+        Self::compute(labels, false)
+    }
+
+    /// Lay out fields with no padding, each starting where the previous one ends.
+    pub const fn packed(labels: [SmallUnsignedLabel; N]) -> Self {
+        Self::compute(labels, true)
+    }
+
+    /// Label of field `idx`. Panics if `idx` is out of bounds.
+    pub const fn label(&self, idx: usize) -> SmallUnsignedLabel {
+        self.labels[idx]
+    }
+
+    /// Byte offset of field `idx`. Panics if `idx` is out of bounds.
+    pub const fn offset(&self, idx: usize) -> usize {
+        self.offsets[idx]
+    }
+
+    /// Byte offsets of every field.
+    pub const fn offsets(&self) -> [usize; N] {
+        self.offsets
+    }
+
+    /// Total size of the layout, in bytes.
+    pub const fn size(&self) -> usize {
+        self.size
+    }
+
+    const fn compute(labels: [SmallUnsignedLabel; N], packed: bool) -> Self {
+        let mut offsets = [0; N];
+        let mut end = 0;
+        let mut max_align = 1;
+        let mut i = 0;
+
+        while i < N {
+            let align = if packed { 1 } else { labels[i].align() };
+            if align > max_align {
+                max_align = align;
+            }
+
+            offsets[i] = round_up(end, align);
+            end = offsets[i] + labels[i].byte_size();
+            i += 1;
+        }
+
+        FieldLayout {
+            labels,
+            offsets,
+            size: round_up(end, max_align),
+        }
+    }
+}
+
+// Round `offset` up to the next multiple of `align` (a power of two).
+const fn round_up(offset: usize, align: usize) -> usize {
+    (offset + align - 1) & !(align - 1)
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{FieldLayout, SmallUnsignedLabel};
+    use core::mem::{align_of, size_of};
+
+    #[test]
+    fn layout_packed_vs_aligned() {
+        let labels = [
+            SmallUnsignedLabel::U8,
+            SmallUnsignedLabel::U16,
+            SmallUnsignedLabel::U8,
+            SmallUnsignedLabel::U32,
+            SmallUnsignedLabel::U8,
+        ];
+
+        let packed = FieldLayout::packed(labels);
+        assert_eq!(packed.offsets(), [0, 1, 3, 4, 8]);
+        assert_eq!(packed.size(), 9);
+
+        let aligned = FieldLayout::aligned(labels);
+        assert_eq!(aligned.offsets(), [0, 2, 4, 8, 12]);
+        assert_eq!(aligned.size(), 16);
+
+        assert_eq!(aligned.label(3), SmallUnsignedLabel::U32);
+        assert_eq!(aligned.offset(3), 8);
+    }
+
+    #[test]
+    fn layout_matches_repr_c() {
+        #[repr(C)]
+        struct Fields {
+            a: u8,
+            b: u64,
+            c: u16,
+        }
+
+        let aligned = FieldLayout::aligned([
+            SmallUnsignedLabel::U8,
+            SmallUnsignedLabel::U64,
+            SmallUnsignedLabel::U16,
+        ]);
+
+        assert_eq!(aligned.offset(1), align_of::<u64>());
+        assert_eq!(aligned.size(), size_of::<Fields>());
+
+        const EMPTY: FieldLayout<0> = FieldLayout::aligned([]);
+        assert_eq!(EMPTY.size(), 0);
+    }
+}
@@ -26,11 +26,11 @@ For signed integers, macro input may be a maximum or a minimum.
 Not safely. The difference is subtle but important:
 
 * `#[repr(packed)]` removes all padding between struct fields.
-This incurs a performance penalty for misaligned accesses at best, and [causes undefined behavior](https://doc.rust-lang.org/nomicon/other-reprs.html#reprpacked) at worst.
-It's something you generally want to avoid.
+  This incurs a performance penalty for misaligned accesses at best, and [causes undefined behavior](https://doc.rust-lang.org/nomicon/other-reprs.html#reprpacked) at worst.
+  It's something you generally want to avoid.
 
 * `smallnum` aids packing while maintaining the target's native alignment, without removing padding.
-It can actually improve [data cache] performance while being fully safe.
+  It can actually improve [data cache] performance while being fully safe.
 
 For extreme size optimization, you're free to combine `smallnum` with `#[repr(packed)]`.
 
@@ -150,6 +150,30 @@ See [`examples/`](https://github.com/tnballo/smallnum/tree/master/examples) dire
 
 * [`small_unsigned!`](crate::small_unsigned) <-> (`u8`, `u16`, `u32`, `u64`, `u128`)
 * [`small_signed!`](crate::small_signed) <-> (`i8`, `i16`, `i32`, `i64`, `i128`)
+* [`small_int!`](crate::small_int) <-> (`u8`, `u16`, `u32`, `u64`, `u128`) or (`i8`, `i16`, `i32`, `i64`, `i128`)
+* [`small_signed_fixed!`](crate::small_signed_fixed) <-> (`i8`, `i16`, `i32`, `i64`, `i128`)
+* [`small_nonzero_unsigned!`](crate::small_nonzero_unsigned) <-> (`NonZeroU8`, `NonZeroU16`, `NonZeroU32`, `NonZeroU64`, `NonZeroU128`)
+* [`small_unsigned_for_range!`](crate::small_unsigned_for_range) <-> (`u8`, `u16`, `u32`, `u64`, `u128`)
+* [`small_unsigned_bits!`](crate::small_unsigned_bits) <-> (`u8`, `u16`, `u32`, `u64`, `u128`)
+* [`small_unsigned_headroom!`](crate::small_unsigned_headroom) <-> (`u8`, `u16`, `u32`, `u64`, `u128`)
+* [`small_unsigned_sum!`](crate::small_unsigned_sum) <-> (`u8`, `u16`, `u32`, `u64`, `u128`)
+* [`small_unsigned_pow2!`](crate::small_unsigned_pow2) <-> (`u8`, `u16`, `u32`, `u64`, `u128`)
+* [`small_unsigned_isa!`](crate::small_unsigned_isa) <-> (`u8`, `u16`, `u32`, `u64`, `u128`, floored per arch)
+* [`small_unsigned_or!`](crate::small_unsigned_or) <-> (`u8`, `u16`, `u32`, `u64`, `u128`, fallback type)
+* [`bounded_saturating!`](crate::bounded_saturating) <-> `BoundedSaturating` over (`u8`, `u16`, `u32`, `u64`, `u128`)
+
+### Optional Features
+
+All features are off by default, `smallnum` has no dependencies unless one is enabled.
+
+* `alloc`: APIs that return heap-allocated values (e.g. `smart_sum`, `emit_struct`, `encode_dynamic`, `bit_reverse_perm`).
+* `derive`: The `#[small_fields]` attribute, which shrinks struct fields annotated `#[small(max = "BOUND")]` and generates `usize` getters/setters. And `#[derive(SmallLayout)]`, for packed layout introspection.
+* `arbitrary`: In-range value generation for structure-aware fuzzing (`arbitrary_small_unsigned`), and `Arbitrary` for `SmallUnsignedLabel` and `SmallSignedLabel`, via [`arbitrary`](https://docs.rs/arbitrary). Meant for fuzz targets and tests, and pulls in `std`, so enable it from dev-dependencies or a fuzz crate rather than a shipped build.
+* `bytemuck`: Zero-copy byte views of small value slices (e.g. `as_bytes`), via [`bytemuck`](https://docs.rs/bytemuck).
+* `defmt`: [`defmt::Format`](https://docs.rs/defmt) for `SmallUnsignedLabel` and `SmallSignedLabel`, as variant names, for logging on embedded targets.
+* `num-traits`: Helpers for generic code bounded by [`num-traits`](https://docs.rs/num-traits) (e.g. `small_zero`). Every type the macros select is a primitive integer, so it also satisfies `PrimInt`.
+* `serde`: `Serialize`/`Deserialize` for `SmallUnsignedLabel` and `SmallSignedLabel`, as variant names (e.g. `"U16"`). And for the `UniqueIndex` and `BoundedSaturating` newtypes, as the underlying primitive. These newtypes are always available (there's no separate `newtype` feature), so `serde` alone gates their impls.
+* `tinyvec`: Helpers for [`tinyvec`](https://docs.rs/tinyvec) stack-allocated buffers (e.g. `push_small`).
 
 ### License and Contributing
 
@@ -157,8 +181,69 @@ Licensed under the [MIT license](https://github.com/tnballo/smallnum/blob/master
 Contributions are welcome!
 */
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "derive")]
+pub use smallnum_derive::{small_fields, SmallLayout};
+
 mod unsigned;
-pub use crate::unsigned::{ShrinkUnsigned, SmallUnsigned, SmallUnsignedLabel};
+pub use crate::unsigned::{
+    assert_selected_bytes, bits_max, fingerprint, for_each_index, headroom_max, isa_floor,
+    label_for_distinct_values, narrow_into, selection_report, small_unsigned_fits, sum_max,
+    wasted_headroom, ShrinkUnsigned, ShrinkUnsignedOr, SmallUnsigned, SmallUnsignedArray,
+    SmallUnsignedLabel,
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::unsigned::bit_reverse_perm;
 
 mod signed;
-pub use crate::signed::{ShrinkSigned, SmallSigned, SmallSignedLabel};
\ No newline at end of file
+pub use crate::signed::{
+    small_signed_fits, ShrinkInt, ShrinkSigned, SmallSigned, SmallSignedLabel,
+};
+
+mod nonzero;
+pub use crate::nonzero::{ShrinkNonZeroUnsigned, SmallNonZeroUnsigned};
+
+mod error;
+pub use crate::error::SmallNumError;
+
+mod codec;
+pub use crate::codec::{read_be_small, read_small};
+
+#[cfg(feature = "alloc")]
+pub use crate::codec::{decode_dynamic, encode_dynamic};
+
+mod bitstream;
+pub use crate::bitstream::{BitReader, BitWriter};
+
+mod index;
+pub use crate::index::{Index, UniqueIndex};
+
+mod layout;
+pub use crate::layout::FieldLayout;
+
+mod bounded;
+pub use crate::bounded::BoundedSaturating;
+
+mod dynamic;
+pub use crate::dynamic::{DynSmall, SmallInt};
+
+#[cfg(feature = "alloc")]
+pub use crate::dynamic::smart_sum;
+
+mod compat;
+#[cfg(feature = "arbitrary")]
+pub use crate::compat::arbitrary::arbitrary_small_unsigned;
+#[cfg(feature = "bytemuck")]
+pub use crate::compat::bytemuck::{as_bytes, try_from_bytes, SmallPod};
+#[cfg(feature = "num-traits")]
+pub use crate::compat::num_traits::{small_max, small_one, small_zero};
+#[cfg(feature = "tinyvec")]
+pub use crate::compat::tinyvec::push_small;
+
+#[cfg(feature = "alloc")]
+mod codegen;
+#[cfg(feature = "alloc")]
+pub use crate::codegen::emit_struct;
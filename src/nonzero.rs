@@ -0,0 +1,200 @@
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+// Non-zero Unsigned Normalization -------------------------------------------------------------------------------------
+
+/// Convenience trait for normalizing non-zero unsigned primitives, the `NonZero*` analogue of `SmallUnsigned`.
+///
+/// The primitives have inherent `get` and `new` methods that take precedence in method call syntax,
+/// so these are meant for generic code (e.g. `T::new(num)` for `T: SmallNonZeroUnsigned`)
+/// or fully-qualified calls (e.g. `SmallNonZeroUnsigned::get(&val)`).
+pub trait SmallNonZeroUnsigned {
+    /// **Upcast:** Get value as host register-width unsigned (e.g. `NonZeroU16` -> `usize`).
+    fn get(&self) -> usize;
+
+    /// **Downcast:** Convert input `usize`, returning `None` if it's zero or exceeds max for the primitive.
+    fn new(num: usize) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+macro_rules! impl_small_nonzero_unsigned {
+    ( $nz:ty, $prim:ty ) => {
+        impl SmallNonZeroUnsigned for $nz {
+            fn get(&self) -> usize {
+                <$nz>::get(*self) as usize
+            }
+
+            fn new(num: usize) -> Option<$nz> {
+                if (num as u128) > (<$prim>::MAX as u128) {
+                    return None;
+                }
+
+                <$nz>::new(num as $prim)
+            }
+        }
+    };
+}
+
+impl_small_nonzero_unsigned!(NonZeroUsize, usize);
+impl_small_nonzero_unsigned!(NonZeroU8, u8);
+
+#[cfg(any(
+    target_pointer_width = "16",
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128",
+))]
+impl_small_nonzero_unsigned!(NonZeroU16, u16);
+
+#[cfg(any(
+    target_pointer_width = "32",
+    target_pointer_width = "64",
+    target_pointer_width = "128",
+))]
+impl_small_nonzero_unsigned!(NonZeroU32, u32);
+
+#[cfg(any(target_pointer_width = "64", target_pointer_width = "128",))]
+impl_small_nonzero_unsigned!(NonZeroU64, u64);
+
+#[cfg(target_pointer_width = "128")]
+impl_small_nonzero_unsigned!(NonZeroU128, u128);
+
+// Compile-time Type Mapping -------------------------------------------------------------------------------------------
+
+/// Return smallest non-zero unsigned type capable of representing `1..=max`.
+/// Unlike `small_unsigned!`, `Option` of the selected type is no larger than the type itself (niche optimization).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{small_nonzero_unsigned, SmallNonZeroUnsigned};
+/// use core::mem::size_of;
+///
+/// type EdgeIdx = small_nonzero_unsigned!(1_000);
+///
+/// let next: Option<EdgeIdx> = EdgeIdx::new(500);
+///
+/// assert_eq!(next.map(|idx| SmallNonZeroUnsigned::get(&idx)), Some(500));
+/// assert_eq!(size_of::<Option<EdgeIdx>>(), size_of::<u16>());
+/// ```
+#[macro_export]
+macro_rules! small_nonzero_unsigned {
+    ( $max:expr $(,)? ) => {
+        <() as $crate::ShrinkNonZeroUnsigned<
+            { ($max as u128) <= (u8::MAX as u128) },
+            { ($max as u128) <= (u16::MAX as u128) },
+            { ($max as u128) <= (u32::MAX as u128) },
+            { ($max as u128) <= (u64::MAX as u128) },
+            { ($max as u128) <= (u128::MAX as u128) },
+        >>::NonZeroType
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper trait for non-zero unsigned type mapping. Internal use only.
+pub trait ShrinkNonZeroUnsigned<
+    const FITS_U8: bool,
+    const FITS_U16: bool,
+    const FITS_U32: bool,
+    const FITS_U64: bool,
+    const FITS_U128: bool,
+>
+{
+    /// Smallest non-zero primitive type that can represent a bounded unsigned value
+    type NonZeroType;
+}
+
+impl ShrinkNonZeroUnsigned<true, true, true, true, true> for () {
+    type NonZeroType = NonZeroU8;
+}
+
+impl ShrinkNonZeroUnsigned<false, true, true, true, true> for () {
+    type NonZeroType = NonZeroU16;
+}
+
+impl ShrinkNonZeroUnsigned<false, false, true, true, true> for () {
+    type NonZeroType = NonZeroU32;
+}
+
+impl ShrinkNonZeroUnsigned<false, false, false, true, true> for () {
+    type NonZeroType = NonZeroU64;
+}
+
+impl ShrinkNonZeroUnsigned<false, false, false, false, true> for () {
+    type NonZeroType = NonZeroU128;
+}
+
+// Test ----------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::SmallNonZeroUnsigned;
+    use core::mem::size_of;
+    use core::num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+    use static_assertions::assert_type_eq_all;
+
+    #[test]
+    fn nonzero_macro() {
+        assert_type_eq_all!(small_nonzero_unsigned!(1), NonZeroU8);
+        assert_type_eq_all!(small_nonzero_unsigned!(255), NonZeroU8);
+        assert_type_eq_all!(small_nonzero_unsigned!(256), NonZeroU16);
+        assert_type_eq_all!(small_nonzero_unsigned!(1_000), NonZeroU16);
+        assert_type_eq_all!(small_nonzero_unsigned!(70_000), NonZeroU32);
+        assert_type_eq_all!(small_nonzero_unsigned!(u64::MAX), NonZeroU64);
+    }
+
+    #[test]
+    fn nonzero_macro_self_bound() {
+        struct Graph;
+
+        impl Graph {
+            const MAX_EDGES: usize = 1_000;
+
+            fn first_edge() -> Option<small_nonzero_unsigned!(Self::MAX_EDGES)> {
+                SmallNonZeroUnsigned::new(1)
+            }
+        }
+
+        assert_eq!(Graph::first_edge(), NonZeroU16::new(1));
+    }
+
+    #[test]
+    fn nonzero_niche() {
+        type EdgeIdx = small_nonzero_unsigned!(1_000);
+
+        assert_eq!(size_of::<Option<EdgeIdx>>(), size_of::<u16>());
+        assert_eq!(size_of::<Option<small_nonzero_unsigned!(200)>>(), 1);
+        assert_eq!(
+            size_of::<Option<crate::small_unsigned!(1_000)>>(),
+            2 * size_of::<u16>()
+        );
+    }
+
+    #[test]
+    fn nonzero_normalize() {
+        type EdgeIdx = small_nonzero_unsigned!(1_000);
+
+        assert_eq!(<EdgeIdx as SmallNonZeroUnsigned>::new(0), None);
+        assert_eq!(<EdgeIdx as SmallNonZeroUnsigned>::new(65_536), None);
+
+        let idx = <EdgeIdx as SmallNonZeroUnsigned>::new(65_535).unwrap();
+        assert_eq!(SmallNonZeroUnsigned::get(&idx), 65_535);
+
+        fn widen_all<T: SmallNonZeroUnsigned>(vals: &[Option<T>]) -> usize {
+            vals.iter().flatten().map(SmallNonZeroUnsigned::get).sum()
+        }
+
+        let vals = [NonZeroU8::new(1), None, NonZeroU8::new(255)];
+        assert_eq!(widen_all(&vals), 256);
+
+        assert_eq!(
+            <NonZeroUsize as SmallNonZeroUnsigned>::new(usize::MAX),
+            NonZeroUsize::new(usize::MAX)
+        );
+        assert_eq!(
+            <NonZeroU32 as SmallNonZeroUnsigned>::new(7),
+            NonZeroU32::new(7)
+        );
+        assert_eq!(<NonZeroU16 as SmallNonZeroUnsigned>::new(0), None);
+    }
+}
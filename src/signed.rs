@@ -1,7 +1,13 @@
+use crate::SmallNumError;
+use core::num::Wrapping;
+
 // Signed Labeling -----------------------------------------------------------------------------------------------------
 
 /// Labels for signed integer primitives.
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SmallSignedLabel {
     /// A label for `isize` types.
     ISIZE,
@@ -28,25 +34,103 @@ impl SmallSignedLabel {
     /// (e.g. `new(-100)` -> `SmallSignedLabel::I8`).
     /// At present, this function does not return the `ISIZE` variant (never needed?).
     pub const fn new(num: isize) -> Self {
-        if (core::i8::MIN as i128 <= (num as i128)) && ((num as i128) <= (core::i8::MAX as i128)) {
+        if (i8::MIN as i128 <= (num as i128)) && ((num as i128) <= (i8::MAX as i128)) {
             SmallSignedLabel::I8
-        } else if (core::i16::MIN as i128 <= (num as i128))
-            && ((num as i128) <= (core::i16::MAX as i128))
-        {
+        } else if (i16::MIN as i128 <= (num as i128)) && ((num as i128) <= (i16::MAX as i128)) {
             SmallSignedLabel::I16
-        } else if (core::i32::MIN as i128 <= (num as i128))
-            && ((num as i128) <= (core::i32::MAX as i128))
-        {
+        } else if (i32::MIN as i128 <= (num as i128)) && ((num as i128) <= (i32::MAX as i128)) {
             SmallSignedLabel::I32
-        } else if (core::i64::MIN as i128 <= (num as i128))
-            && ((num as i128) <= (core::i64::MAX as i128))
-        {
+        } else if (i64::MIN as i128 <= (num as i128)) && ((num as i128) <= (i64::MAX as i128)) {
             SmallSignedLabel::I64
         } else {
-            // (core::i128::MIN as i128 <= (num as i128)) && ((num as i128) <= (core::i128::MAX as i128))
+            // (i128::MIN as i128 <= (num as i128)) && ((num as i128) <= (i128::MAX as i128))
             SmallSignedLabel::I128
         }
     }
+
+    /// Name of the Rust primitive this label represents (e.g. `SmallSignedLabel::I16` -> `"i16"`).
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            SmallSignedLabel::ISIZE => "isize",
+            SmallSignedLabel::I8 => "i8",
+            SmallSignedLabel::I16 => "i16",
+            SmallSignedLabel::I32 => "i32",
+            SmallSignedLabel::I64 => "i64",
+            SmallSignedLabel::I128 => "i128",
+        }
+    }
+
+    /// Min value of the represented type (e.g. `SmallSignedLabel::I8` -> `-128`).
+    pub const fn min_value(&self) -> i128 {
+        match self {
+            SmallSignedLabel::ISIZE => isize::MIN as i128,
+            SmallSignedLabel::I8 => i8::MIN as i128,
+            SmallSignedLabel::I16 => i16::MIN as i128,
+            SmallSignedLabel::I32 => i32::MIN as i128,
+            SmallSignedLabel::I64 => i64::MIN as i128,
+            SmallSignedLabel::I128 => i128::MIN,
+        }
+    }
+
+    /// Max value of the represented type (e.g. `SmallSignedLabel::I8` -> `127`).
+    pub const fn max_value(&self) -> i128 {
+        match self {
+            SmallSignedLabel::ISIZE => isize::MAX as i128,
+            SmallSignedLabel::I8 => i8::MAX as i128,
+            SmallSignedLabel::I16 => i16::MAX as i128,
+            SmallSignedLabel::I32 => i32::MAX as i128,
+            SmallSignedLabel::I64 => i64::MAX as i128,
+            SmallSignedLabel::I128 => i128::MAX,
+        }
+    }
+
+    /// Size of the represented type, in bytes (e.g. `SmallSignedLabel::I32` -> `4`).
+    /// Usable in const context, e.g. to lay out packed metadata without hardcoding sizes.
+    pub const fn byte_size(self) -> usize {
+        match self {
+            SmallSignedLabel::ISIZE => core::mem::size_of::<isize>(),
+            SmallSignedLabel::I8 => 1,
+            SmallSignedLabel::I16 => 2,
+            SmallSignedLabel::I32 => 4,
+            SmallSignedLabel::I64 => 8,
+            SmallSignedLabel::I128 => 16,
+        }
+    }
+
+    /// Returns `true` if both labels represent the same memory layout.
+    /// Unlike `==`, `ISIZE` is resolved to the host's width first (e.g. `ISIZE.layout_eq(I64)` on a 64-bit host).
+    pub const fn layout_eq(self, other: Self) -> bool {
+        (self.host_resolved() as u8) == (other.host_resolved() as u8)
+    }
+
+    // Resolve `ISIZE` to the fixed-width variant with the host's width.
+    const fn host_resolved(self) -> Self {
+        match self {
+            SmallSignedLabel::ISIZE => SmallSignedLabel::new(isize::MAX),
+            _ => self,
+        }
+    }
+}
+
+// Const Comparison ----------------------------------------------------------------------------------------------------
+
+/// Returns `true` if `val` fits the type `small_signed!(bound)` selects.
+/// Usable in const context, e.g. to relate a default or sentinel constant to the bound it will be stored under.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_signed_fits;
+///
+/// const MIN_OFFSET: isize = -100;
+/// const SENTINEL: isize = -128;
+///
+/// // `small_signed!(MIN_OFFSET)` is `i8`, so `SENTINEL` can be stored
+/// const _: () = assert!(small_signed_fits(SENTINEL, MIN_OFFSET));
+/// ```
+pub const fn small_signed_fits(val: isize, bound: isize) -> bool {
+    let label = SmallSignedLabel::new(bound);
+    (label.min_value() <= (val as i128)) && ((val as i128) <= label.max_value())
 }
 
 // Signed Normalization ------------------------------------------------------------------------------------------------
@@ -69,6 +153,203 @@ pub trait SmallSigned {
     /// Unlike others, this API has a tiny (1 comparison/branch) runtime cost.
     /// The `check` in `checked_from` is an `assert` to prevent loss of precision.
     fn checked_from(num: isize) -> Self;
+
+    /// Width of the underlying primitive, in bits (e.g. `8` for `i8`).
+    const BITS: u32;
+
+    /// Width of the underlying primitive, in bits (i.e. `Self::BITS`, not the value's significant bits).
+    /// For layout diagnostics in generic code that has a value but no label.
+    fn bit_width(&self) -> u32 {
+        Self::BITS
+    }
+
+    /// **Downcast:** Convert input `i64` into a primitive implementing the `SmallSigned` trait.
+    /// Unlike `checked_from`, source width doesn't depend on the host (e.g. for values read from a file).
+    /// Panics if `i64` exceeds min/max for returned signed primitive, including on hosts where `isize` is narrower.
+    fn checked_from_i64(num: i64) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(i64_fits_width(num, isize::BITS));
+        Self::checked_from(num as isize)
+    }
+
+    /// **Downcast:** Convert input `isize` into a primitive implementing the `SmallSigned` trait.
+    /// Unlike `checked_from`, never panics: returns an error carrying the value and target label if it doesn't fit
+    /// (e.g. for sizes from untrusted input), `SignedUnderflow` below the min and `SignedOverflow` above the max.
+    ///
+    /// ### Note
+    ///
+    /// In edition 2021+, `TryFrom` is in the prelude, so `i8::try_from(num)` is ambiguous with this trait in scope.
+    /// Disambiguate with `SmallSigned::try_from(num)` or `<i8 as SmallSigned>::try_from(num)`.
+    fn try_from(num: isize) -> Result<Self, SmallNumError>
+    where
+        Self: Sized,
+    {
+        let (min, max) = (type_min::<Self>(), type_max::<Self>());
+        let label = SmallSignedLabel::new(max);
+        if num < min {
+            return Err(SmallNumError::SignedUnderflow { value: num, label });
+        }
+        if num > max {
+            return Err(SmallNumError::SignedOverflow { value: num, label });
+        }
+
+        Ok(Self::checked_from(num))
+    }
+
+    /// **Downcast:** Convert input `isize`, clamping to min/max for the primitive instead of panicking.
+    fn saturating_from(num: isize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from(num.clamp(type_min::<Self>(), type_max::<Self>()))
+    }
+
+    /// Add `rhs` to this value, returning `None` if the result exceeds min/max for the primitive.
+    /// Computed in `i128`, so neither the addition nor a too-large `rhs` can overflow before the check.
+    fn checked_add_isize(&self, rhs: isize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        narrow_i128((self.isize() as i128) + (rhs as i128))
+    }
+
+    /// Subtract `rhs` from this value, returning `None` if the result exceeds min/max for the primitive.
+    /// Computed in `i128`, so neither the subtraction nor a too-large `rhs` can overflow before the check.
+    fn checked_sub_isize(&self, rhs: isize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        narrow_i128((self.isize() as i128) - (rhs as i128))
+    }
+
+    /// **Upcast:** Sign-extend every element of `src` into `dst`. Panics if lengths differ.
+    /// Lengths are checked once up front, so the loop body is bounds-check free and can be autovectorized.
+    fn widen_slice_into(src: &[Self], dst: &mut [isize])
+    where
+        Self: Sized,
+    {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.isize();
+        }
+    }
+
+    /// Interpret value as fixed-point with `frac_bits` fractional bits (e.g. `384_i16` in Q8.8 -> `1.5`).
+    /// Panics if `frac_bits` is `128` or more.
+    fn fixed_to_f64(&self, frac_bits: u32) -> f64 {
+        (self.isize() as f64) / fixed_scale(frac_bits)
+    }
+
+    /// **Downcast:** Scale `val` to fixed-point with `frac_bits` fractional bits, rounding to nearest
+    /// (half away from zero). Values outside the range of the returned signed primitive are clamped to its min/max.
+    /// Panics if `val` is NaN or `frac_bits` is `128` or more.
+    fn fixed_from_f64(val: f64, frac_bits: u32) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(!val.is_nan(), "fixed_from_f64: val is NaN");
+
+        let rounded = round_f64(val * fixed_scale(frac_bits), Rounding::Nearest);
+        let clamped = rounded.clamp(type_min::<Self>() as i128, type_max::<Self>() as i128);
+        Self::checked_from(clamped as isize)
+    }
+
+    /// **Downcast:** Convert `val` to the nearest integer, rounding half away from zero (e.g. `-2.5` -> `-3`).
+    /// Returns `None` if `val` is NaN or the result exceeds min/max for the returned signed primitive.
+    fn from_f64_round(val: f64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        from_f64_rounded(val, Rounding::Nearest)
+    }
+
+    /// **Downcast:** Convert `val` to the largest integer less than or equal to it (e.g. `-2.5` -> `-3`).
+    /// Returns `None` if `val` is NaN or the result exceeds min/max for the returned signed primitive.
+    fn from_f64_floor(val: f64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        from_f64_rounded(val, Rounding::Floor)
+    }
+
+    /// **Downcast:** Convert `val` to the smallest integer greater than or equal to it (e.g. `-2.5` -> `-2`).
+    /// Returns `None` if `val` is NaN or the result exceeds min/max for the returned signed primitive.
+    fn from_f64_ceil(val: f64) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        from_f64_rounded(val, Rounding::Ceil)
+    }
+}
+
+// Whether `num` fits a signed primitive of `bits` width, e.g. `isize` on a host of that width.
+// Parameterized so the narrower-host path is testable on any host.
+fn i64_fits_width(num: i64, bits: u32) -> bool {
+    let shift = i128::BITS - bits;
+    ((i128::MIN >> shift) <= (num as i128)) && ((num as i128) <= (i128::MAX >> shift))
+}
+
+// Rounding modes for float conversion.
+enum Rounding {
+    Nearest,
+    Floor,
+    Ceil,
+}
+
+// No `f64::{floor, ceil, round}` in `core`, so round relative to the truncated value (casts truncate toward zero).
+// Saturates at the bounds of `i128`.
+fn round_f64(val: f64, mode: Rounding) -> i128 {
+    let trunc = val as i128;
+    let frac = val - (trunc as f64);
+    match mode {
+        Rounding::Nearest if frac >= 0.5 => trunc.saturating_add(1),
+        Rounding::Nearest if frac <= -0.5 => trunc.saturating_sub(1),
+        Rounding::Floor if frac < 0.0 => trunc.saturating_sub(1),
+        Rounding::Ceil if frac > 0.0 => trunc.saturating_add(1),
+        _ => trunc,
+    }
+}
+
+fn from_f64_rounded<T: SmallSigned>(val: f64, mode: Rounding) -> Option<T> {
+    let (min, max) = (type_min::<T>() as i128, type_max::<T>() as i128);
+
+    // Coarse pre-check, also rejects NaN and infinities. Exact bounds are checked after rounding.
+    if !((((min - 1) as f64) <= val) && (val <= ((max + 1) as f64))) {
+        return None;
+    }
+
+    let rounded = round_f64(val, mode);
+    if (rounded < min) || (rounded > max) {
+        return None;
+    }
+
+    Some(T::checked_from(rounded as isize))
+}
+
+// Scale factor for `frac_bits` fractional bits, i.e. `2^frac_bits`.
+fn fixed_scale(frac_bits: u32) -> f64 {
+    assert!(frac_bits < u128::BITS, "frac_bits must be below 128");
+    (1_u128 << frac_bits) as f64
+}
+
+// Narrow an `i128` intermediate to `T`, checking both bounds.
+fn narrow_i128<T: SmallSigned>(num: i128) -> Option<T> {
+    if ((type_min::<T>() as i128) <= num) && (num <= (type_max::<T>() as i128)) {
+        Some(T::checked_from(num as isize))
+    } else {
+        None
+    }
+}
+
+// Min/max of the primitive underlying `T`, as an `isize` (impls never exceed host width).
+fn type_min<T: SmallSigned + ?Sized>() -> isize {
+    isize::MIN >> (isize::BITS - T::BITS)
+}
+
+fn type_max<T: SmallSigned + ?Sized>() -> isize {
+    isize::MAX >> (isize::BITS - T::BITS)
 }
 
 impl SmallSigned for isize {
@@ -79,6 +360,8 @@ impl SmallSigned for isize {
     fn checked_from(num: isize) -> isize {
         num
     }
+
+    const BITS: u32 = isize::BITS;
 }
 
 impl SmallSigned for i8 {
@@ -90,6 +373,8 @@ impl SmallSigned for i8 {
         assert!((i8::MIN as isize <= num) && (num <= i8::MAX as isize));
         num as i8
     }
+
+    const BITS: u32 = i8::BITS;
 }
 
 #[cfg(any(
@@ -107,6 +392,8 @@ impl SmallSigned for i16 {
         assert!((i16::MIN as isize <= num) && (num <= i16::MAX as isize));
         num as i16
     }
+
+    const BITS: u32 = i16::BITS;
 }
 
 #[cfg(any(
@@ -123,6 +410,8 @@ impl SmallSigned for i32 {
         assert!((i32::MIN as isize <= num) && (num <= i32::MAX as isize));
         num as i32
     }
+
+    const BITS: u32 = i32::BITS;
 }
 
 #[cfg(any(target_pointer_width = "64", target_pointer_width = "128",))]
@@ -135,6 +424,8 @@ impl SmallSigned for i64 {
         assert!((i64::MIN as isize <= num) && (num <= i64::MAX as isize));
         num as i64
     }
+
+    const BITS: u32 = i64::BITS;
 }
 
 #[cfg(target_pointer_width = "128")]
@@ -145,8 +436,24 @@ impl SmallSigned for i128 {
 
     fn checked_from(num: isize) -> Self {
         assert!((i128::MIN as isize <= num) && (num <= i128::MAX as isize));
-        num as i28
+        num as i128
     }
+
+    const BITS: u32 = i128::BITS;
+}
+
+/// Forwards to the wrapped primitive.
+/// References need no impl of their own: methods take `&self` (e.g. `slice.iter().map(SmallSigned::isize)`).
+impl<T: SmallSigned> SmallSigned for Wrapping<T> {
+    fn isize(&self) -> isize {
+        self.0.isize()
+    }
+
+    fn checked_from(num: isize) -> Wrapping<T> {
+        Wrapping(T::checked_from(num))
+    }
+
+    const BITS: u32 = T::BITS;
 }
 
 // Compile-time Type Mapping -------------------------------------------------------------------------------------------
@@ -171,30 +478,54 @@ impl SmallSigned for i128 {
 /// assert_eq!(val_neg, small_val_neg.isize());
 /// assert!(size_of_val(&val_neg) > size_of_val(&small_val_neg));
 /// ```
+///
+/// For asymmetric ranges, pass both inclusive bounds: the selected type covers each of them.
+///
+/// ```
+/// use smallnum::small_signed;
+/// use core::mem::size_of;
+///
+/// // `-10` alone fits `i8`, but `40_000` needs `i32`
+/// assert_eq!(size_of::<small_signed!(-10, 40_000)>(), 4);
+/// ```
 #[macro_export]
 macro_rules! small_signed {
-    ( $val:expr $(,)? ) => {
+    ( $min:expr, $max:expr $(,)? ) => {
         <() as $crate::ShrinkSigned<
             {
-                (core::i8::MIN as i128 <= ($val as i128))
-                    && (($val as i128) <= (core::i8::MAX as i128))
+                (i8::MIN as i128 <= ($min as i128))
+                    && (($min as i128) <= (i8::MAX as i128))
+                    && (i8::MIN as i128 <= ($max as i128))
+                    && (($max as i128) <= (i8::MAX as i128))
             },
             {
-                (core::i16::MIN as i128 <= ($val as i128))
-                    && (($val as i128) <= (core::i16::MAX as i128))
+                (i16::MIN as i128 <= ($min as i128))
+                    && (($min as i128) <= (i16::MAX as i128))
+                    && (i16::MIN as i128 <= ($max as i128))
+                    && (($max as i128) <= (i16::MAX as i128))
             },
             {
-                (core::i32::MIN as i128 <= ($val as i128))
-                    && (($val as i128) <= (core::i32::MAX as i128))
+                (i32::MIN as i128 <= ($min as i128))
+                    && (($min as i128) <= (i32::MAX as i128))
+                    && (i32::MIN as i128 <= ($max as i128))
+                    && (($max as i128) <= (i32::MAX as i128))
             },
             {
-                (core::i64::MIN as i128 <= ($val as i128))
-                    && (($val as i128) <= (core::i64::MAX as i128))
-            },
-            {
-                (core::i128::MIN as i128 <= ($val as i128))
-                    && (($val as i128) <= (core::i128::MAX as i128))
+                (i64::MIN as i128 <= ($min as i128))
+                    && (($min as i128) <= (i64::MAX as i128))
+                    && (i64::MIN as i128 <= ($max as i128))
+                    && (($max as i128) <= (i64::MAX as i128))
             },
+            { (i128::MIN as i128 <= ($min as i128)) && (($max as i128) <= (i128::MAX as i128)) },
+        >>::SmallSigned
+    };
+    ( $val:expr $(,)? ) => {
+        <() as $crate::ShrinkSigned<
+            { (i8::MIN as i128 <= ($val as i128)) && (($val as i128) <= (i8::MAX as i128)) },
+            { (i16::MIN as i128 <= ($val as i128)) && (($val as i128) <= (i16::MAX as i128)) },
+            { (i32::MIN as i128 <= ($val as i128)) && (($val as i128) <= (i32::MAX as i128)) },
+            { (i64::MIN as i128 <= ($val as i128)) && (($val as i128) <= (i64::MAX as i128)) },
+            { (i128::MIN as i128 <= ($val as i128)) && (($val as i128) <= (i128::MAX as i128)) },
         >>::SmallSigned
     };
 }
@@ -233,6 +564,95 @@ impl ShrinkSigned<false, false, false, false, true> for () {
     type SmallSigned = i128;
 }
 
+// Compile-time Type Mapping (Fixed-Point) ----------------------------------------------------------------------------
+
+/// Return smallest signed type capable of representing fixed-point values with whole part in `-max_whole..=max_whole`
+/// and `frac_bits` fractional bits, i.e. a raw magnitude of up to `max_whole << frac_bits`.
+/// Use with `SmallSigned::fixed_to_f64` and `SmallSigned::fixed_from_f64`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{small_signed_fixed, SmallSigned};
+/// use core::mem::size_of;
+///
+/// // Q8.8
+/// type Q8_8 = small_signed_fixed!(127, 8);
+///
+/// let val = Q8_8::fixed_from_f64(-1.5, 8);
+/// assert_eq!(val.fixed_to_f64(8), -1.5);
+/// assert_eq!(size_of::<Q8_8>(), 2);
+/// ```
+#[macro_export]
+macro_rules! small_signed_fixed {
+    ( $max_whole:expr, $frac_bits:expr $(,)? ) => {
+        $crate::small_signed!(($max_whole as i128) << ($frac_bits))
+    };
+}
+
+// Compile-time Type Mapping (Either Sign) -----------------------------------------------------------------------------
+
+/// Return smallest type capable of representing input value, picking the sign from the value itself:
+/// unsigned (as `small_unsigned!`) if it's non-negative, signed (as `small_signed!`) if it's negative.
+///
+/// Selection happens entirely at compile-time, the bound must be const and within `i128`'s range
+/// (a bound above `i128::MAX` fails to compile).
+/// A negative bound can never select an unsigned type, so there's no way to end up with a type that can't hold it.
+/// Note a non-negative bound only guarantees room for `0..=bound`: use `small_signed!` for a signed type with
+/// positive bounds.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_int;
+/// use core::mem::size_of;
+///
+/// let pos: small_int!(300) = 300_u16;
+/// let neg: small_int!(-300) = -300_i16;
+///
+/// assert_eq!(size_of::<small_int!(300)>(), 2);
+/// assert_eq!(size_of::<small_int!(-300)>(), 2);
+/// ```
+#[macro_export]
+macro_rules! small_int {
+    ( $val:expr $(,)? ) => {
+        <(
+            // Not `small_unsigned!`, its `as u128` cast can't take a negative literal
+            <() as $crate::ShrinkUnsigned<
+                // `as i128` wraps a bound above `i128::MAX` negative, only the first predicate reports it
+                {
+                    #[allow(unused_comparisons, clippy::absurd_extreme_comparisons)]
+                    let is_neg = $val < 0;
+                    if is_neg != (($val as i128) < 0) {
+                        panic!("small_int!: bound exceeds i128::MAX");
+                    }
+                    ($val as i128) <= (u8::MAX as i128)
+                },
+                { ($val as i128) <= (u16::MAX as i128) },
+                { ($val as i128) <= (u32::MAX as i128) },
+                { ($val as i128) <= (u64::MAX as i128) },
+                { ($val as i128) <= i128::MAX },
+            >>::UnsignedType,
+            $crate::small_signed!($val),
+        ) as $crate::ShrinkInt<{ ($val as i128) < 0 }>>::IntType
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper trait for sign-agnostic type mapping, picks from an `(unsigned, signed)` pair. Internal use only.
+pub trait ShrinkInt<const IS_NEG: bool> {
+    /// Smallest primitive type that can represent a bounded value of either sign
+    type IntType;
+}
+
+impl<U, S> ShrinkInt<false> for (U, S) {
+    type IntType = U;
+}
+
+impl<U, S> ShrinkInt<true> for (U, S) {
+    type IntType = S;
+}
+
 // Compile-time Label Mapping ------------------------------------------------------------------------------------------
 
 /// Return a label corresponding to the smallest type capable of representing input value
@@ -252,7 +672,7 @@ impl ShrinkSigned<false, false, false, false, true> for () {
 #[macro_export]
 macro_rules! small_signed_label {
     ( $max:expr $(,)? ) => {
-        SmallSignedLabel::new($max)
+        $crate::SmallSignedLabel::new($max)
     };
 }
 
@@ -261,8 +681,9 @@ macro_rules! small_signed_label {
 #[cfg(test)]
 mod tests {
 
-    use crate::{SmallSigned, SmallSignedLabel};
+    use crate::{small_signed_fits, SmallNumError, SmallSigned, SmallSignedLabel};
     use core::mem::size_of;
+    use core::num::Wrapping;
     use static_assertions::assert_type_eq_all;
 
     const MAX_VAL_SIGNED: isize = 150;
@@ -327,7 +748,7 @@ mod tests {
         assert_eq!(size_of::<I128TypePos>(), 16);
 
         #[cfg(target_pointer_width = "128")]
-        assert_eq!(size_of::<I128TypePos>(), size_of()::<128TypeNeg>());
+        assert_eq!(size_of::<I128TypePos>(), size_of::<I128TypeNeg>());
 
         // Normalization Check (to isize) ------------------------------------------------------------------------------
 
@@ -341,12 +762,12 @@ mod tests {
         #[cfg(target_pointer_width = "128")]
         let i128_num_pos: I128TypePos = 9_300_000_000_000_000_000;
 
-        assert_eq!(i8_num_pos.isize(), 100 as isize);
-        assert_eq!(i16_num_pos.isize(), 500 as isize);
-        assert_eq!(i32_num_pos.isize(), 50_000 as isize);
+        assert_eq!(i8_num_pos.isize(), 100_isize);
+        assert_eq!(i16_num_pos.isize(), 500_isize);
+        assert_eq!(i32_num_pos.isize(), 50_000_isize);
 
         #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
-        assert_eq!(i64_num_pos.isize(), 2_200_000_000 as isize);
+        assert_eq!(i64_num_pos.isize(), 2_200_000_000_isize);
 
         #[cfg(target_pointer_width = "128")]
         assert_eq!(i128_num_pos.isize(), 9_300_000_000_000_000_000 as isize);
@@ -361,37 +782,31 @@ mod tests {
         #[cfg(target_pointer_width = "128")]
         let i128_num_neg: I128TypeNeg = -9_300_000_000_000_000_000;
 
-        assert_eq!(i8_num_neg.isize(), -100 as isize);
-        assert_eq!(i16_num_neg.isize(), -500 as isize);
-        assert_eq!(i32_num_neg.isize(), -50_000 as isize);
+        assert_eq!(i8_num_neg.isize(), -100_isize);
+        assert_eq!(i16_num_neg.isize(), -500_isize);
+        assert_eq!(i32_num_neg.isize(), -50_000_isize);
 
         #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
-        assert_eq!(i64_num_neg.isize(), -2_200_000_000 as isize);
+        assert_eq!(i64_num_neg.isize(), -2_200_000_000_isize);
 
         #[cfg(target_pointer_width = "128")]
         assert_eq!(i128_num_neg.isize(), -9_300_000_000_000_000_000 as isize);
 
         // Normalization Check (From isize) ----------------------------------------------------------------------------
 
-        assert_eq!(100 as i8, i8::checked_from(100 as isize));
-        assert_eq!(-100 as i8, i8::checked_from(-100 as isize));
+        assert_eq!(100_i8, i8::checked_from(100_isize));
+        assert_eq!(-100_i8, i8::checked_from(-100_isize));
 
-        assert_eq!(500 as i16, i16::checked_from(500 as isize));
-        assert_eq!(-500 as i16, i16::checked_from(-500 as isize));
+        assert_eq!(500_i16, i16::checked_from(500_isize));
+        assert_eq!(-500_i16, i16::checked_from(-500_isize));
 
-        assert_eq!(50_00 as i16, i16::checked_from(50_00 as isize));
-        assert_eq!(-50_00 as i16, i16::checked_from(-50_00 as isize));
+        assert_eq!(50_000_i32, i32::checked_from(50_000_isize));
+        assert_eq!(-50_000_i32, i32::checked_from(-50_000_isize));
 
         #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
         {
-            assert_eq!(
-                2_200_000_000 as i64,
-                i64::checked_from(2_200_000_000 as isize)
-            );
-            assert_eq!(
-                -2_200_000_000 as i64,
-                i64::checked_from(-2_200_000_000 as isize)
-            );
+            assert_eq!(2_200_000_000_i64, i64::checked_from(2_200_000_000_isize));
+            assert_eq!(-2_200_000_000_i64, i64::checked_from(-2_200_000_000_isize));
         }
 
         #[cfg(target_pointer_width = "128")]
@@ -457,4 +872,504 @@ mod tests {
         #[cfg(target_pointer_width = "128")]
         assert_eq!(i128_label_neg, SmallSignedLabel::I128);
     }
+
+    #[test]
+    #[should_panic]
+    fn signed_checked_from_overflow() {
+        let _ = i8::checked_from(128);
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_checked_from_underflow() {
+        let _ = i16::checked_from(-32_769);
+    }
+
+    #[test]
+    fn signed_checked_from_generic() {
+        // Free-list style code generic over the signed index type
+        fn next_links<T: SmallSigned + Copy>(len: usize) -> [T; 4] {
+            let mut links = [T::checked_from(-1); 4];
+            for (i, link) in links.iter_mut().enumerate().take(len.saturating_sub(1)) {
+                *link = T::checked_from((i + 1) as isize);
+            }
+            links
+        }
+
+        assert_eq!(next_links::<i8>(4), [1, 2, 3, -1]);
+        assert_eq!(next_links::<small_signed!(-1_000)>(2), [1, -1, -1, -1]);
+    }
+
+    #[test]
+    fn signed_try_from() {
+        type I8Type = small_signed!(-100);
+
+        assert_eq!(I8Type::try_from(-128), Ok(-128));
+        assert_eq!(I8Type::try_from(127), Ok(127));
+        assert_eq!(
+            I8Type::try_from(128),
+            Err(SmallNumError::SignedOverflow {
+                value: 128,
+                label: SmallSignedLabel::I8
+            })
+        );
+        assert_eq!(
+            i16::try_from(-40_000),
+            Err(SmallNumError::SignedUnderflow {
+                value: -40_000,
+                label: SmallSignedLabel::I16
+            })
+        );
+
+        // Either end fails, distinguishably
+        assert_eq!(
+            i8::try_from(-200),
+            Err(SmallNumError::SignedUnderflow {
+                value: -200,
+                label: SmallSignedLabel::I8
+            })
+        );
+        assert_eq!(
+            i8::try_from(200),
+            Err(SmallNumError::SignedOverflow {
+                value: 200,
+                label: SmallSignedLabel::I8
+            })
+        );
+        assert_eq!(isize::try_from(isize::MIN), Ok(isize::MIN));
+    }
+
+    #[test]
+    fn signed_bit_width() {
+        // Generic, so inherent methods on the primitives can't shadow it
+        fn width<T: SmallSigned>(val: T) -> u32 {
+            val.bit_width()
+        }
+
+        assert_eq!(width(-1_i8), 8);
+        assert_eq!(width(i8::MIN), 8);
+        assert_eq!(width(1_i16), 16);
+        assert_eq!(width(1_i32), 32);
+        assert_eq!(width(1_isize), isize::BITS);
+        assert_eq!(width(Wrapping(1_i16)), 16);
+        assert_eq!(width::<small_signed!(-200)>(-3), 16);
+
+        #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
+        assert_eq!(width(1_i64), 64);
+
+        #[cfg(target_pointer_width = "128")]
+        assert_eq!(width(1_i128), 128);
+    }
+
+    #[test]
+    fn signed_saturating_from() {
+        assert_eq!(i8::saturating_from(300), 127);
+        assert_eq!(i8::saturating_from(-300), -128);
+        assert_eq!(i8::saturating_from(-42), -42);
+        assert_eq!(<small_signed!(-200)>::saturating_from(isize::MIN), i16::MIN);
+        assert_eq!(isize::saturating_from(isize::MIN), isize::MIN);
+    }
+
+    #[test]
+    fn signed_checked_add_sub_isize() {
+        let val: i8 = 100;
+
+        assert_eq!(val.checked_add_isize(27), Some(127));
+        assert_eq!(val.checked_add_isize(28), None);
+        assert_eq!(val.checked_sub_isize(228), Some(-128));
+        assert_eq!(val.checked_sub_isize(229), None);
+
+        let val: i8 = -100;
+        assert_eq!(val.checked_add_isize(-28), Some(-128));
+        assert_eq!(val.checked_add_isize(-29), None);
+        assert_eq!(val.checked_sub_isize(-227), Some(127));
+        assert_eq!(val.checked_sub_isize(-228), None);
+
+        // `rhs` exceeds the primitive, but the result fits
+        assert_eq!((-100_i8).checked_add_isize(200), Some(100));
+        assert_eq!(10_i8.checked_sub_isize(isize::MIN), None);
+        assert_eq!(i16::MIN.checked_add_isize(40_000), Some(7_232));
+
+        // Host width, intermediate can't overflow
+        assert_eq!(isize::MAX.checked_add_isize(1), None);
+        assert_eq!(isize::MIN.checked_sub_isize(1), None);
+        assert_eq!(isize::MIN.checked_sub_isize(isize::MIN), Some(0));
+    }
+
+    #[test]
+    fn signed_label_type_name() {
+        assert_eq!(SmallSignedLabel::new(-200).type_name(), "i16");
+        assert_eq!(SmallSignedLabel::ISIZE.type_name(), "isize");
+        assert_eq!(SmallSignedLabel::I8.type_name(), "i8");
+        assert_eq!(SmallSignedLabel::I16.type_name(), "i16");
+        assert_eq!(SmallSignedLabel::I32.type_name(), "i32");
+        assert_eq!(SmallSignedLabel::I64.type_name(), "i64");
+        assert_eq!(SmallSignedLabel::I128.type_name(), "i128");
+    }
+
+    #[test]
+    fn signed_label_new() {
+        assert_eq!(SmallSignedLabel::new(-200), SmallSignedLabel::I16);
+        assert_eq!(SmallSignedLabel::new(100), SmallSignedLabel::I8);
+        assert_eq!(SmallSignedLabel::new(-128), SmallSignedLabel::I8);
+        assert_eq!(SmallSignedLabel::new(128), SmallSignedLabel::I16);
+
+        // Symmetric with type mapping
+        assert_eq!(size_of::<small_signed!(-200)>(), size_of::<i16>());
+        assert_eq!(size_of::<small_signed!(100)>(), size_of::<i8>());
+    }
+
+    // No `SmallSignedLabel` in scope, the macro must resolve it itself
+    mod label_hygiene {
+        #[test]
+        fn signed_label_macro_hygiene() {
+            assert_eq!(
+                crate::small_signed_label!(-200),
+                crate::SmallSignedLabel::I16
+            );
+        }
+    }
+
+    #[test]
+    fn signed_from_i64() {
+        type I16Type = small_signed!(-500);
+
+        assert_eq!(I16Type::checked_from_i64(-500_i64), -500_i16);
+        assert_eq!(I16Type::checked_from_i64(i16::MIN as i64), i16::MIN);
+        assert_eq!(I16Type::checked_from_i64(i16::MAX as i64), i16::MAX);
+        assert_eq!(isize::checked_from_i64(i32::MIN as i64), i32::MIN as isize);
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_from_i64_underflow() {
+        type I16Type = small_signed!(-500);
+
+        I16Type::checked_from_i64(i16::MIN as i64 - 1);
+    }
+
+    #[test]
+    fn signed_from_i64_width() {
+        // 32-bit host: `isize` is `i32`-wide
+        assert!(super::i64_fits_width(i32::MIN as i64, 32));
+        assert!(super::i64_fits_width(i32::MAX as i64, 32));
+        assert!(!super::i64_fits_width(i32::MIN as i64 - 1, 32));
+        assert!(!super::i64_fits_width(i32::MAX as i64 + 1, 32));
+
+        // 64-bit host: every `i64` fits
+        assert!(super::i64_fits_width(i64::MIN, 64));
+        assert!(super::i64_fits_width(i64::MAX, 64));
+
+        // 16-bit host
+        assert!(super::i64_fits_width(i16::MIN as i64, 16));
+        assert!(!super::i64_fits_width(i16::MAX as i64 + 1, 16));
+    }
+
+    // Fits the host's `isize`, but not the target: rejected by the narrowing assert, not truncated.
+    // The narrower-host path is covered by `signed_from_i64_width`.
+    #[test]
+    #[should_panic]
+    fn signed_from_i64_narrow_overflow() {
+        type I32Type = small_signed!(50_000);
+
+        I32Type::checked_from_i64(i32::MAX as i64 + 1);
+    }
+
+    #[test]
+    fn signed_widen_slice_into() {
+        let src: [i16; 5] = [i16::MIN, -1, 0, 1, i16::MAX];
+        let mut dst = [0_isize; 5];
+
+        i16::widen_slice_into(&src, &mut dst);
+        assert_eq!(dst, [i16::MIN as isize, -1, 0, 1, i16::MAX as isize]);
+
+        let deltas: [i8; 4] = [-128, 5, -3, 127];
+        let mut wide = [0_isize; 4];
+        i8::widen_slice_into(&deltas, &mut wide);
+        assert_eq!(wide.iter().sum::<isize>(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_widen_slice_into_len_mismatch() {
+        let mut dst = [0_isize; 1];
+        i8::widen_slice_into(&[-1, 1], &mut dst);
+    }
+
+    #[test]
+    fn signed_wrapping() {
+        type I8Type = small_signed!(-100);
+
+        let vals: [Wrapping<I8Type>; 3] = [Wrapping(-100), Wrapping(0), Wrapping(127)];
+        let widened = vals.iter().map(SmallSigned::isize);
+        assert!(widened.eq([-100, 0, 127].iter().copied()));
+
+        let sum = vals[2] + Wrapping(1);
+        assert_eq!(sum.isize(), -128);
+
+        assert_eq!(Wrapping::<I8Type>::checked_from(-5), Wrapping(-5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn signed_wrapping_checked_from_overflow() {
+        let _ = Wrapping::<i8>::checked_from(-129);
+    }
+
+    #[test]
+    fn signed_macro_boundaries() {
+        // Exact cliff edges, both directions
+        assert_type_eq_all!(small_signed!(-128), i8);
+        assert_type_eq_all!(small_signed!(-129), i16);
+        assert_type_eq_all!(small_signed!(127), i8);
+        assert_type_eq_all!(small_signed!(128), i16);
+
+        assert_type_eq_all!(small_signed!(i16::MIN), i16);
+        assert_type_eq_all!(small_signed!((i16::MIN as i128) - 1), i32);
+        assert_type_eq_all!(small_signed!(i16::MAX), i16);
+        assert_type_eq_all!(small_signed!((i16::MAX as i128) + 1), i32);
+
+        assert_type_eq_all!(small_signed!(i32::MIN), i32);
+        assert_type_eq_all!(small_signed!((i32::MIN as i128) - 1), i64);
+        assert_type_eq_all!(small_signed!(i32::MAX), i32);
+        assert_type_eq_all!(small_signed!((i32::MAX as i128) + 1), i64);
+
+        assert_type_eq_all!(small_signed!(i64::MIN), i64);
+        assert_type_eq_all!(small_signed!((i64::MIN as i128) - 1), i128);
+        assert_type_eq_all!(small_signed!(i64::MAX), i64);
+        assert_type_eq_all!(small_signed!((i64::MAX as i128) + 1), i128);
+
+        assert_type_eq_all!(small_signed!(i128::MIN), i128);
+        assert_type_eq_all!(small_signed!(i128::MAX), i128);
+
+        // Range form at the same edges
+        assert_type_eq_all!(small_signed!(i8::MIN, i8::MAX), i8);
+        assert_type_eq_all!(small_signed!(-129, 0), i16);
+        assert_type_eq_all!(small_signed!(i128::MIN, i128::MAX), i128);
+
+        // Labels agree
+        assert_eq!(SmallSignedLabel::new(-128), SmallSignedLabel::I8);
+        assert_eq!(SmallSignedLabel::new(-129), SmallSignedLabel::I16);
+        assert_eq!(
+            SmallSignedLabel::new(i32::MIN as isize),
+            SmallSignedLabel::I32
+        );
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            SmallSignedLabel::new((i32::MIN as isize) - 1),
+            SmallSignedLabel::I64
+        );
+    }
+
+    #[test]
+    fn signed_macro_range() {
+        assert_type_eq_all!(small_signed!(-1, 200), i16);
+        assert_type_eq_all!(small_signed!(-10, 40_000), i32);
+        assert_type_eq_all!(small_signed!(-128, 127), i8);
+        assert_type_eq_all!(small_signed!(-129, 127), i16);
+        assert_type_eq_all!(small_signed!(-40_000, 10), i32);
+        assert_type_eq_all!(small_signed!(i64::MIN, 0), i64);
+        assert_type_eq_all!(small_signed!(0, i128::MAX,), i128);
+
+        // Same as single-bound form when only one bound matters
+        assert_type_eq_all!(small_signed!(0, -500), small_signed!(-500));
+    }
+
+    #[test]
+    fn int_macro() {
+        const NEG_BOUND: isize = -40_000;
+
+        assert_type_eq_all!(small_int!(0), u8);
+        assert_type_eq_all!(small_int!(255), u8);
+        assert_type_eq_all!(small_int!(300), u16);
+        assert_type_eq_all!(small_int!(u64::MAX), u64);
+        assert_type_eq_all!(small_int!((u64::MAX as i128) + 1), u128);
+        assert_type_eq_all!(small_int!(i128::MAX), u128);
+        assert_type_eq_all!(small_int!(200_u128), u8);
+        assert_type_eq_all!(small_int!(-1), i8);
+        assert_type_eq_all!(small_int!(-128), i8);
+        assert_type_eq_all!(small_int!(-300), i16);
+        assert_type_eq_all!(small_int!(NEG_BOUND), i32);
+        assert_type_eq_all!(small_int!(i64::MIN), i64);
+    }
+
+    #[test]
+    fn signed_fixed_macro() {
+        type Q8_8 = small_signed_fixed!(127, 8);
+        type Q16_16 = small_signed_fixed!(32_767, 16);
+        type Q4_4 = small_signed_fixed!(7, 4);
+
+        assert_type_eq_all!(Q8_8, i16);
+        assert_type_eq_all!(Q16_16, i32);
+        assert_type_eq_all!(Q4_4, i8);
+
+        // Whole part past 127 no longer fits Q8.8 storage
+        type Wider = small_signed_fixed!(128, 8);
+        assert_type_eq_all!(Wider, i32);
+    }
+
+    #[test]
+    fn signed_fixed_round_trip() {
+        type Q8_8 = small_signed_fixed!(127, 8);
+        const TOLERANCE: f64 = 1.0 / 256.0;
+
+        for val in [0.0, 1.5, -1.5, 0.3, -100.01, 127.99] {
+            let fixed = Q8_8::fixed_from_f64(val, 8);
+            let back = fixed.fixed_to_f64(8);
+            let err = if back > val { back - val } else { val - back };
+            assert!(err <= TOLERANCE, "{} -> {} -> {}", val, fixed, back);
+        }
+
+        // Exact values, rounding to nearest
+        assert_eq!(Q8_8::fixed_from_f64(1.5, 8), 384);
+        assert_eq!(Q8_8::fixed_from_f64(-1.5, 8), -384);
+        assert_eq!(Q8_8::fixed_from_f64(0.001, 8), 0);
+        assert_eq!(Q8_8::fixed_from_f64(0.003, 8), 1);
+        assert_eq!(Q8_8::fixed_from_f64(-0.003, 8), -1);
+
+        // Clamped to storage range
+        assert_eq!(Q8_8::fixed_from_f64(1_000.0, 8), i16::MAX);
+        assert_eq!(Q8_8::fixed_from_f64(-1_000.0, 8), i16::MIN);
+        assert_eq!(i8::fixed_from_f64(f64::INFINITY, 0), i8::MAX);
+        assert_eq!(i8::fixed_from_f64(f64::NEG_INFINITY, 127), i8::MIN);
+
+        // Largest double below 0.5 rounds down (adding 0.5 would round it up to 1)
+        assert_eq!(i8::fixed_from_f64(0.49999999999999994, 0), 0);
+        assert_eq!(i8::fixed_from_f64(-0.49999999999999994, 0), 0);
+        assert_eq!(i8::fixed_from_f64(0.5, 0), 1);
+        assert_eq!(i8::fixed_from_f64(-0.5, 0), -1);
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn signed_fixed_nan() {
+        let _ = i16::fixed_from_f64(f64::NAN, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "frac_bits")]
+    fn signed_fixed_frac_bits_too_wide() {
+        let _ = i16::fixed_from_f64(1.0, 128);
+    }
+
+    #[test]
+    fn signed_from_f64_rounding() {
+        type I8Type = small_signed!(-100);
+
+        assert_eq!(I8Type::from_f64_round(2.5), Some(3));
+        assert_eq!(I8Type::from_f64_round(-2.5), Some(-3));
+        assert_eq!(I8Type::from_f64_round(2.4), Some(2));
+        assert_eq!(I8Type::from_f64_round(-2.4), Some(-2));
+
+        assert_eq!(I8Type::from_f64_floor(2.5), Some(2));
+        assert_eq!(I8Type::from_f64_floor(-2.5), Some(-3));
+        assert_eq!(I8Type::from_f64_floor(-3.0), Some(-3));
+
+        assert_eq!(I8Type::from_f64_ceil(2.5), Some(3));
+        assert_eq!(I8Type::from_f64_ceil(-2.5), Some(-2));
+        assert_eq!(I8Type::from_f64_ceil(3.0), Some(3));
+
+        // Edges of `i8`
+        assert_eq!(I8Type::from_f64_floor(127.9), Some(127));
+        assert_eq!(I8Type::from_f64_ceil(-128.9), Some(-128));
+        assert_eq!(I8Type::from_f64_round(-128.4), Some(-128));
+
+        // Out of range
+        assert_eq!(I8Type::from_f64_ceil(127.1), None);
+        assert_eq!(I8Type::from_f64_round(127.5), None);
+        assert_eq!(I8Type::from_f64_floor(-128.1), None);
+        assert_eq!(I8Type::from_f64_round(1e10), None);
+        assert_eq!(I8Type::from_f64_round(f64::INFINITY), None);
+        assert_eq!(I8Type::from_f64_floor(f64::NEG_INFINITY), None);
+        assert_eq!(I8Type::from_f64_round(f64::NAN), None);
+        assert_eq!(isize::from_f64_round(f64::NAN), None);
+        assert_eq!(isize::from_f64_floor(1e30), None);
+
+        assert_eq!(i16::from_f64_round(-300.5), Some(-301));
+        assert_eq!(isize::from_f64_floor(isize::MIN as f64), Some(isize::MIN));
+        assert_eq!(isize::from_f64_ceil(isize::MAX as f64), None);
+    }
+
+    #[test]
+    fn signed_const_cmp() {
+        const SENTINEL_FITS: bool = small_signed_fits(-128, -100);
+        const SENTINEL_UNDERFLOWS: bool = small_signed_fits(-129, -100);
+
+        const _: () = assert!(SENTINEL_FITS);
+        const _: () = assert!(!SENTINEL_UNDERFLOWS);
+        const _: () = assert!(small_signed_fits(i16::MAX as isize, MIN_VAL_SIGNED));
+
+        assert_eq!(SmallSignedLabel::I8.min_value(), -128);
+        assert_eq!(SmallSignedLabel::I8.max_value(), 127);
+        assert_eq!(SmallSignedLabel::I16.min_value(), i16::MIN as i128);
+        assert_eq!(SmallSignedLabel::I16.max_value(), i16::MAX as i128);
+        assert_eq!(SmallSignedLabel::I128.min_value(), i128::MIN);
+        assert_eq!(SmallSignedLabel::I128.max_value(), i128::MAX);
+        assert_eq!(SmallSignedLabel::ISIZE.min_value(), isize::MIN as i128);
+        assert_eq!(SmallSignedLabel::ISIZE.max_value(), isize::MAX as i128);
+
+        assert!(small_signed_fits(127, 100));
+        assert!(!small_signed_fits(128, 100));
+        assert!(small_signed_fits(isize::MIN, isize::MIN));
+    }
+
+    #[test]
+    fn signed_label_byte_size() {
+        const OFFSET_BYTES: usize = SmallSignedLabel::new(-500).byte_size();
+        assert_eq!(OFFSET_BYTES, 2);
+
+        assert_eq!(SmallSignedLabel::I8.byte_size(), size_of::<i8>());
+        assert_eq!(SmallSignedLabel::I16.byte_size(), size_of::<i16>());
+        assert_eq!(SmallSignedLabel::I32.byte_size(), size_of::<i32>());
+        assert_eq!(SmallSignedLabel::I64.byte_size(), size_of::<i64>());
+        assert_eq!(SmallSignedLabel::I128.byte_size(), size_of::<i128>());
+        assert_eq!(SmallSignedLabel::ISIZE.byte_size(), size_of::<isize>());
+    }
+
+    #[test]
+    fn signed_label_layout_eq() {
+        assert!(SmallSignedLabel::I16.layout_eq(SmallSignedLabel::I16));
+        assert!(!SmallSignedLabel::I16.layout_eq(SmallSignedLabel::I32));
+        assert!(SmallSignedLabel::ISIZE.layout_eq(SmallSignedLabel::ISIZE));
+        assert_ne!(SmallSignedLabel::ISIZE, SmallSignedLabel::I64);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert!(SmallSignedLabel::ISIZE.layout_eq(SmallSignedLabel::I64));
+            assert!(SmallSignedLabel::I64.layout_eq(SmallSignedLabel::ISIZE));
+            assert!(!SmallSignedLabel::ISIZE.layout_eq(SmallSignedLabel::I32));
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        assert!(SmallSignedLabel::ISIZE.layout_eq(SmallSignedLabel::I32));
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn signed_label_defmt() {
+        // Encoding needs a global logger (i.e. an embedded target), so only check the impl exists on host
+        static_assertions::assert_impl_all!(SmallSignedLabel: defmt::Format);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn signed_label_serde() {
+        let json = serde_json::to_string(&SmallSignedLabel::I16).unwrap();
+        assert_eq!(json, "\"I16\"");
+        assert_eq!(
+            serde_json::from_str::<SmallSignedLabel>(&json).unwrap(),
+            SmallSignedLabel::I16
+        );
+
+        let json = serde_json::to_string(&SmallSignedLabel::ISIZE).unwrap();
+        assert_eq!(json, "\"ISIZE\"");
+        assert_eq!(
+            serde_json::from_str::<SmallSignedLabel>(&json).unwrap(),
+            SmallSignedLabel::ISIZE
+        );
+
+        // Unknown variant is an error, not a panic
+        assert!(serde_json::from_str::<SmallSignedLabel>("\"I7\"").is_err());
+        assert!(serde_json::from_str::<SmallSignedLabel>("16").is_err());
+    }
 }
@@ -1,7 +1,18 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::{Index, SmallNumError};
+use core::alloc::{Layout, LayoutError};
+use core::cmp::Reverse;
+use core::num::{NonZeroUsize, Wrapping};
+
 // Unsigned Labeling ---------------------------------------------------------------------------------------------------
 
 /// Labels for unsigned integer primitives.
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum SmallUnsignedLabel {
     /// A label for `usize` types.
     USIZE,
@@ -22,25 +33,273 @@ pub enum SmallUnsignedLabel {
     U128,
 }
 
-// TODO: return USIZE based on host width?
 impl SmallUnsignedLabel {
     /// Maps input `usize` to label for smallest integer primitive capable of representing it
     /// (e.g. `new(100)` -> `SmallUnsignedLabel::U8`).
-    /// At present, this function does not return the `USIZE` variant (never needed?).
+    /// This function never returns the `USIZE` variant, see `new_native` for that.
     pub const fn new(num: usize) -> Self {
-        if (num as u128) <= (core::u8::MAX as u128) {
+        if (num as u128) <= (u8::MAX as u128) {
             SmallUnsignedLabel::U8
-        } else if (num as u128) <= (core::u16::MAX as u128) {
+        } else if (num as u128) <= (u16::MAX as u128) {
             SmallUnsignedLabel::U16
-        } else if (num as u128) <= (core::u32::MAX as u128) {
+        } else if (num as u128) <= (u32::MAX as u128) {
             SmallUnsignedLabel::U32
-        } else if (num as u128) <= (core::u64::MAX as u128) {
+        } else if (num as u128) <= (u64::MAX as u128) {
             SmallUnsignedLabel::U64
         } else {
-            // (num as u128) <= (core::u128::MAX as u128)
+            // (num as u128) <= (u128::MAX as u128)
             SmallUnsignedLabel::U128
         }
     }
+
+    /// Same as `new`, but returns `USIZE` if the selected primitive is as wide as the host's `usize`
+    /// (e.g. `new_native(60_000)` -> `SmallUnsignedLabel::USIZE` on a 16-bit host), so downstream code can store
+    /// a plain `usize` and skip the conversion.
+    pub const fn new_native(num: usize) -> Self {
+        let label = Self::new(num);
+        if label.bit_width() == usize::BITS {
+            SmallUnsignedLabel::USIZE
+        } else {
+            label
+        }
+    }
+
+    /// Name of the Rust primitive this label represents (e.g. `SmallUnsignedLabel::U16` -> `"u16"`).
+    pub const fn type_name(self) -> &'static str {
+        match self {
+            SmallUnsignedLabel::USIZE => "usize",
+            SmallUnsignedLabel::U8 => "u8",
+            SmallUnsignedLabel::U16 => "u16",
+            SmallUnsignedLabel::U32 => "u32",
+            SmallUnsignedLabel::U64 => "u64",
+            SmallUnsignedLabel::U128 => "u128",
+        }
+    }
+
+    /// Schema-style descriptor of the represented type (e.g. `SmallUnsignedLabel::U16` -> `"uint16"`),
+    /// for tooling that documents binary formats. `USIZE` is described by the host's width.
+    pub const fn describe(&self) -> &'static str {
+        match self {
+            SmallUnsignedLabel::USIZE => self.host_resolved().describe(),
+            SmallUnsignedLabel::U8 => "uint8",
+            SmallUnsignedLabel::U16 => "uint16",
+            SmallUnsignedLabel::U32 => "uint32",
+            SmallUnsignedLabel::U64 => "uint64",
+            SmallUnsignedLabel::U128 => "uint128",
+        }
+    }
+
+    /// Max value of the represented type (e.g. `SmallUnsignedLabel::U8` -> `255`).
+    pub const fn max_value(&self) -> u128 {
+        match self {
+            SmallUnsignedLabel::USIZE => usize::MAX as u128,
+            SmallUnsignedLabel::U8 => u8::MAX as u128,
+            SmallUnsignedLabel::U16 => u16::MAX as u128,
+            SmallUnsignedLabel::U32 => u32::MAX as u128,
+            SmallUnsignedLabel::U64 => u64::MAX as u128,
+            SmallUnsignedLabel::U128 => u128::MAX,
+        }
+    }
+
+    /// Number of distinct values the represented type can hold, i.e. `max_value + 1` (e.g. `U8` -> `256`).
+    /// Saturates at `u128::MAX` for `U128` (and `USIZE` on 128-bit hosts), whose true count doesn't fit.
+    pub const fn max_distinct_values(&self) -> u128 {
+        self.max_value().saturating_add(1)
+    }
+
+    /// Returns `true` if both labels represent the same memory layout.
+    /// Unlike `==`, `USIZE` is resolved to the host's width first (e.g. `USIZE.layout_eq(U64)` on a 64-bit host).
+    pub const fn layout_eq(self, other: Self) -> bool {
+        (self.host_resolved() as u8) == (other.host_resolved() as u8)
+    }
+
+    /// Memory layout of `[T; n]`, where `T` is the represented type (e.g. for arena allocators).
+    /// Errors under the same conditions as `Layout::array`.
+    pub fn array_layout(&self, n: usize) -> Result<Layout, LayoutError> {
+        match self {
+            SmallUnsignedLabel::USIZE => Layout::array::<usize>(n),
+            SmallUnsignedLabel::U8 => Layout::array::<u8>(n),
+            SmallUnsignedLabel::U16 => Layout::array::<u16>(n),
+            SmallUnsignedLabel::U32 => Layout::array::<u32>(n),
+            SmallUnsignedLabel::U64 => Layout::array::<u64>(n),
+            SmallUnsignedLabel::U128 => Layout::array::<u128>(n),
+        }
+    }
+
+    /// Size of the represented type, in bytes (e.g. `SmallUnsignedLabel::U32` -> `4`).
+    /// Usable in const context, e.g. to lay out packed metadata without hardcoding sizes.
+    pub const fn byte_size(self) -> usize {
+        match self {
+            SmallUnsignedLabel::USIZE => core::mem::size_of::<usize>(),
+            SmallUnsignedLabel::U8 => 1,
+            SmallUnsignedLabel::U16 => 2,
+            SmallUnsignedLabel::U32 => 4,
+            SmallUnsignedLabel::U64 => 8,
+            SmallUnsignedLabel::U128 => 16,
+        }
+    }
+
+    /// Width of the represented type, in bits (e.g. `SmallUnsignedLabel::U16` -> `16`).
+    /// Exact width of the representation, not the bits a value requires (compare `small_unsigned_bits!`).
+    pub const fn bit_width(self) -> u32 {
+        match self {
+            SmallUnsignedLabel::USIZE => usize::BITS,
+            SmallUnsignedLabel::U8 => u8::BITS,
+            SmallUnsignedLabel::U16 => u16::BITS,
+            SmallUnsignedLabel::U32 => u32::BITS,
+            SmallUnsignedLabel::U64 => u64::BITS,
+            SmallUnsignedLabel::U128 => u128::BITS,
+        }
+    }
+
+    // Native alignment of the represented type, in bytes.
+    pub(crate) const fn align(self) -> usize {
+        match self {
+            SmallUnsignedLabel::USIZE => core::mem::align_of::<usize>(),
+            SmallUnsignedLabel::U8 => core::mem::align_of::<u8>(),
+            SmallUnsignedLabel::U16 => core::mem::align_of::<u16>(),
+            SmallUnsignedLabel::U32 => core::mem::align_of::<u32>(),
+            SmallUnsignedLabel::U64 => core::mem::align_of::<u64>(),
+            SmallUnsignedLabel::U128 => core::mem::align_of::<u128>(),
+        }
+    }
+
+    // Resolve `USIZE` to the fixed-width variant with the host's width.
+    const fn host_resolved(self) -> Self {
+        match self {
+            SmallUnsignedLabel::USIZE => SmallUnsignedLabel::new(usize::MAX),
+            _ => self,
+        }
+    }
+}
+
+/// Label for the smallest unsigned primitive that can represent `count` distinct values, i.e. indexes `0..count`
+/// (e.g. `256` -> `SmallUnsignedLabel::U8`, `257` -> `SmallUnsignedLabel::U16`).
+/// A `count` of `0` needs no values at all, so maps to the smallest primitive, `SmallUnsignedLabel::U8`.
+pub const fn label_for_distinct_values(count: usize) -> SmallUnsignedLabel {
+    match count {
+        0 => SmallUnsignedLabel::U8,
+        _ => SmallUnsignedLabel::new(count - 1),
+    }
+}
+
+/// Stable 64-bit hash (FNV-1a) of a label sequence, e.g. the field types of a stored record.
+/// Store it alongside the data and compare against the current schema at startup to detect drift.
+/// Field order matters, and `USIZE` hashes as itself (not as the host's width), so the result is host-independent.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{fingerprint, SmallUnsignedLabel};
+///
+/// const SCHEMA: u64 = fingerprint(&[SmallUnsignedLabel::U8, SmallUnsignedLabel::U32]);
+///
+/// assert_eq!(SCHEMA, fingerprint(&[SmallUnsignedLabel::U8, SmallUnsignedLabel::U32]));
+/// assert_ne!(SCHEMA, fingerprint(&[SmallUnsignedLabel::U32, SmallUnsignedLabel::U8]));
+/// ```
+pub const fn fingerprint(labels: &[SmallUnsignedLabel]) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mut hash = FNV_OFFSET;
+    let mut i = 0;
+    while i < labels.len() {
+        // Explicit codes, so reordering the enum can't change stored fingerprints
+        let code: u8 = match labels[i] {
+            SmallUnsignedLabel::USIZE => 0,
+            SmallUnsignedLabel::U8 => 1,
+            SmallUnsignedLabel::U16 => 2,
+            SmallUnsignedLabel::U32 => 3,
+            SmallUnsignedLabel::U64 => 4,
+            SmallUnsignedLabel::U128 => 5,
+        };
+
+        hash ^= code as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Every fixed-width candidate `small_unsigned!` considers, smallest first, and whether `max` fits it.
+/// The first fitting candidate is the one selected (e.g. for debug output explaining a selection).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{selection_report, SmallUnsignedLabel};
+///
+/// let report = selection_report(1_000);
+///
+/// assert_eq!(report[0], (SmallUnsignedLabel::U8, false));
+/// assert_eq!(report[1], (SmallUnsignedLabel::U16, true));
+/// ```
+pub const fn selection_report(max: u128) -> [(SmallUnsignedLabel, bool); 5] {
+    [
+        (SmallUnsignedLabel::U8, max <= (u8::MAX as u128)),
+        (SmallUnsignedLabel::U16, max <= (u16::MAX as u128)),
+        (SmallUnsignedLabel::U32, max <= (u32::MAX as u128)),
+        (SmallUnsignedLabel::U64, max <= (u64::MAX as u128)),
+        (SmallUnsignedLabel::U128, true),
+    ]
+}
+
+/// Values of the type `small_unsigned!(max)` selects that are left unused, i.e. `selected_type_max - max`
+/// (e.g. `256` selects `u16`, wasting `65_279`). A large result means `max` is just over a boundary.
+pub const fn wasted_headroom(max: u128) -> u128 {
+    selected_label(max).max_value() - max
+}
+
+// Label of the type `small_unsigned!(max)` selects, i.e. the first fitting candidate in the selection report.
+const fn selected_label(max: u128) -> SmallUnsignedLabel {
+    let report = selection_report(max);
+
+    // `U128` always fits, so the loop terminates
+    let mut i = 0;
+    while !report[i].1 {
+        i += 1;
+    }
+
+    report[i].0
+}
+
+// Const Comparison ----------------------------------------------------------------------------------------------------
+
+/// Returns `true` if `val` fits the type `small_unsigned!(max)` selects.
+/// Usable in const context, e.g. to relate a default or sentinel constant to the bound it will be stored under.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_fits;
+///
+/// const MAX_CAPACITY: usize = 200;
+/// const SENTINEL: usize = 255;
+///
+/// // `small_unsigned!(MAX_CAPACITY)` is `u8`, so `SENTINEL` can be stored
+/// const _: () = assert!(small_unsigned_fits(SENTINEL, MAX_CAPACITY));
+/// ```
+pub const fn small_unsigned_fits(val: usize, max: usize) -> bool {
+    (val as u128) <= SmallUnsignedLabel::new(max).max_value()
+}
+
+/// Returns `true` if the type `small_unsigned!(max)` selects is `expected` bytes wide.
+/// Usable in const context, e.g. as a format-stability guard that fails the build if a bound change would
+/// silently alter a serialized or `repr(C)` layout.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::assert_selected_bytes;
+///
+/// const MAX_RECORDS: u128 = 60_000;
+///
+/// // On-disk record IDs are 2 bytes
+/// const _: () = assert!(assert_selected_bytes(MAX_RECORDS, 2));
+/// ```
+pub const fn assert_selected_bytes(max: u128, expected: usize) -> bool {
+    selected_label(max).byte_size() == expected
 }
 
 // Unsigned Normalization ----------------------------------------------------------------------------------------------
@@ -63,6 +322,452 @@ pub trait SmallUnsigned {
     /// Unlike others, this API has a tiny (1 comparison/branch) runtime cost.
     /// The `check` in `checked_from` is an `assert` to prevent loss of precision.
     fn checked_from(num: usize) -> Self;
+
+    /// **Downcast:** Convert input `usize` into a primitive implementing the `SmallUnsigned` trait.
+    /// Unlike `checked_from`, never panics: returns an error carrying the value and target max if it doesn't fit
+    /// (e.g. for sizes from untrusted input).
+    ///
+    /// ### Note
+    ///
+    /// In edition 2021+, `TryFrom` is in the prelude, so `u8::try_from(num)` is ambiguous with this trait in scope.
+    /// Disambiguate with `SmallUnsigned::try_from(num)` or `<u8 as SmallUnsigned>::try_from(num)`.
+    fn try_from(num: usize) -> Result<Self, SmallNumError>
+    where
+        Self: Sized,
+    {
+        let max = Self::MAX;
+        if num > max {
+            return Err(SmallNumError::overflow(num, max));
+        }
+
+        Ok(Self::checked_from(num))
+    }
+
+    /// Distance over the limit, `num - Self::MAX`, if `num` exceeds max for the primitive, else `None`
+    /// (e.g. to report by how much a rejected value overflowed).
+    fn overflow_amount(num: usize) -> Option<usize> {
+        num.checked_sub(Self::MAX).filter(|over| *over > 0)
+    }
+
+    /// **Downcast:** Convert input `usize`, clamping to max for the primitive instead of panicking
+    /// (e.g. a counter displayed as "255+"). `NonZeroUsize` still panics on `0`.
+    fn saturating_from(num: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from(num.min(Self::MAX))
+    }
+
+    /// **Downcast:** Convert input `usize`, truncating out-of-range input to the low bits, same as an `as` cast
+    /// (e.g. `300` -> `44_u8`). No bound check, for hot paths where the caller already validated the input.
+    /// Truncation silently loses precision, but is never undefined behavior.
+    ///
+    /// ### Note
+    ///
+    /// Not `const`: trait methods can't be on stable Rust.
+    /// `NonZeroUsize` has no low bits to truncate to, it still panics on `0`.
+    #[doc(alias = "new_unchecked")]
+    fn truncating_from(num: usize) -> Self
+    where
+        Self: Sized,
+    {
+        // Masking makes `checked_from`'s assert always pass, the compiler can drop it
+        Self::checked_from(num & Self::MAX)
+    }
+
+    /// **Downcast:** Same as `truncating_from`, under its original name.
+    #[deprecated(
+        note = "renamed to `truncating_from`: it truncates, unlike the `unsafe` `new_unchecked` of `core::num`"
+    )]
+    fn new_unchecked(num: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::truncating_from(num)
+    }
+
+    /// **Downcast:** Convert input `u64` into a primitive implementing the `SmallUnsigned` trait.
+    /// Unlike `checked_from`, source width doesn't depend on the host (e.g. for values read from a file).
+    /// Panics if `u64` exceeds max for returned unsigned primitive, including on hosts where `usize` is narrower.
+    fn checked_from_u64(num: u64) -> Self
+    where
+        Self: Sized,
+    {
+        assert!(u64_fits_width(num, usize::BITS));
+        Self::checked_from(num as usize)
+    }
+
+    /// Write value into `buf` as exactly `Self::BYTES` little-endian bytes (e.g. for packed serialization).
+    /// Panics if `buf.len()` isn't `Self::BYTES`.
+    fn write_le_bytes(&self, buf: &mut [u8]) {
+        assert_eq!(buf.len(), Self::BYTES);
+        buf.copy_from_slice(&self.usize().to_le_bytes()[..Self::BYTES]);
+    }
+
+    /// Write value into `buf` as exactly `Self::BYTES` big-endian bytes (e.g. for network byte order).
+    /// Panics if `buf.len()` isn't `Self::BYTES`.
+    fn write_be_bytes(&self, buf: &mut [u8]) {
+        assert_eq!(buf.len(), Self::BYTES);
+        buf.copy_from_slice(
+            &self.usize().to_be_bytes()[(core::mem::size_of::<usize>() - Self::BYTES)..],
+        );
+    }
+
+    /// Read a value from exactly `Self::BYTES` little-endian bytes, the inverse of `write_le_bytes`.
+    /// Panics if `buf.len()` isn't `Self::BYTES`, or if the value is invalid for the type (via `checked_from`).
+    fn read_le_bytes(buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        assert_eq!(buf.len(), Self::BYTES);
+        Self::checked_from(
+            buf.iter()
+                .rev()
+                .fold(0, |acc, byte| (acc << 8) | (*byte as usize)),
+        )
+    }
+
+    /// Read a value from exactly `Self::BYTES` big-endian bytes, the inverse of `write_be_bytes`.
+    /// Panics if `buf.len()` isn't `Self::BYTES`, or if the value is invalid for the type (via `checked_from`).
+    fn read_be_bytes(buf: &[u8]) -> Self
+    where
+        Self: Sized,
+    {
+        assert_eq!(buf.len(), Self::BYTES);
+        Self::checked_from(
+            buf.iter()
+                .fold(0, |acc, byte| (acc << 8) | (*byte as usize)),
+        )
+    }
+
+    /// **Downcast:** Convert to another small unsigned type, via `usize` (e.g. re-indexing from a
+    /// `small_unsigned!(BIG)` collection into a `small_unsigned!(SMALL)` one).
+    /// Returns `None` if value exceeds `T::MAX` or is otherwise invalid for `T` (e.g. zero for `NonZeroUsize`).
+    fn narrow<T: SmallUnsigned>(&self) -> Option<T> {
+        T::try_from(self.usize()).ok()
+    }
+
+    /// **Downcast:** Narrow the magnitude of a sign-magnitude value (e.g. a field decoded from a wire format),
+    /// passing its sign through: `(300, true)` -> `Some((300_u16, true))`, `true` meaning negative.
+    /// Negative zero and magnitudes above `i128::MAX` are kept as-is, no signed intermediate is built.
+    /// Returns `None` if `mag` exceeds max for the primitive.
+    fn from_magnitude(mag: u128, negative: bool) -> Option<(Self, bool)>
+    where
+        Self: Sized,
+    {
+        if mag > (Self::MAX as u128) {
+            return None;
+        }
+
+        Some((Self::checked_from(mag as usize), negative))
+    }
+
+    /// **Downcast:** Split a signed value into its narrowed magnitude and sign, as `from_magnitude`
+    /// (e.g. `-300` -> `Some((300_u16, true))`). Returns `None` if the magnitude exceeds max for the primitive.
+    fn try_from_i128_signed_magnitude(num: i128) -> Option<(Self, bool)>
+    where
+        Self: Sized,
+    {
+        Self::from_magnitude(num.unsigned_abs(), num < 0)
+    }
+
+    /// Width of the underlying primitive, in bits (e.g. `8` for `u8`).
+    const BITS: u32;
+
+    /// Width of the underlying primitive, in bytes (e.g. `2` for `u16`).
+    const BYTES: usize = (Self::BITS / 8) as usize;
+
+    /// Max value of the underlying primitive, as a `usize` (e.g. `255` for `u8`).
+    /// For generic bound checks before `checked_from`, e.g. on indexes derived from external data.
+    const MAX: usize = usize::MAX >> (usize::BITS - Self::BITS);
+
+    /// Width of the underlying primitive, in bits (i.e. `Self::BITS`, not the value's significant bits).
+    /// For layout diagnostics in generic code that has a value but no label.
+    fn bit_width(&self) -> u32 {
+        Self::BITS
+    }
+
+    /// Label of the underlying primitive (not of the value, e.g. `0_u16` -> `SmallUnsignedLabel::U16`).
+    /// For generic code that records which width it's operating on. `usize` reports `USIZE`, not its fixed width.
+    fn label(&self) -> SmallUnsignedLabel {
+        SmallUnsignedLabel::new(Self::MAX)
+    }
+
+    /// Array with one `bool` per bit of the underlying primitive, i.e. `[bool; Self::BITS]`.
+    type BitArray: AsRef<[bool]> + AsMut<[bool]>;
+
+    /// Expand value into its bits, least significant first (i.e. `bits[0]` is bit 0).
+    fn to_bits(&self) -> Self::BitArray;
+
+    /// Pack bits, least significant first, back into a value (inverse of `to_bits`).
+    fn from_bits(bits: Self::BitArray) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from(
+            bits.as_ref()
+                .iter()
+                .rev()
+                .fold(0, |num, bit| (num << 1) | (*bit as usize)),
+        )
+    }
+
+    /// Returns `true` if value is `0`.
+    fn is_zero(&self) -> bool {
+        self.usize() == 0
+    }
+
+    /// Returns `true` if value is the max of the underlying primitive (e.g. `255` for `u8`).
+    /// Handy for detecting sentinels.
+    fn is_type_max(&self) -> bool {
+        self.usize() == Self::MAX
+    }
+
+    /// Euclidean remainder of value by `rhs`, computed as `usize` and narrowed back.
+    /// Result never exceeds value, so narrowing can't fail for zero-inclusive types.
+    /// Panics if `rhs` is `0`, or if the result is invalid for the type (e.g. zero for `NonZeroUsize`).
+    fn rem_euclid_usize(&self, rhs: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from(self.usize().rem_euclid(rhs))
+    }
+
+    /// Euclidean quotient of value by `rhs`, computed as `usize` and narrowed back.
+    /// Result never exceeds value, so narrowing can't fail for zero-inclusive types.
+    /// Panics if `rhs` is `0`, or if the result is invalid for the type (e.g. zero for `NonZeroUsize`).
+    fn div_euclid_usize(&self, rhs: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from(self.usize().div_euclid(rhs))
+    }
+
+    /// Modular complement of value, `(modulus - value) % modulus` (e.g. `capacity - index` in a ring).
+    /// Panics if `modulus` is `0`, if value exceeds `modulus`, or if the result exceeds max for the primitive.
+    fn complement(&self, modulus: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from((modulus - self.usize()) % modulus)
+    }
+
+    /// Subtract `rhs`, wrapping around at the bounds of the underlying primitive (e.g. `0_u8` minus `1` -> `255`).
+    /// Panics if the result is invalid for the type (e.g. zero for `NonZeroUsize`).
+    fn wrapping_sub_usize(&self, rhs: usize) -> Self
+    where
+        Self: Sized,
+    {
+        Self::truncating_from(self.usize().wrapping_sub(rhs))
+    }
+
+    /// Forward distance from value to `other` on a ring of `modulus` slots (e.g. from head to tail index of a
+    /// circular buffer), always in `0..modulus`. Both values are reduced modulo `modulus` first.
+    /// Panics if `modulus` is `0`.
+    fn circular_distance(&self, other: &Self, modulus: usize) -> usize {
+        let (from, to) = (self.usize() % modulus, other.usize() % modulus);
+        if to >= from {
+            to - from
+        } else {
+            modulus - (from - to)
+        }
+    }
+
+    /// **Downcast:** Row-major flat index `row * stride + col`, for dense 2D buffers of `bound` elements.
+    /// Returns `None` if `col` isn't within the row (`col >= stride`), the computation overflows, the result isn't
+    /// below `bound`, or it exceeds max for the primitive.
+    fn flat_index(row: usize, col: usize, stride: usize, bound: usize) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        if col >= stride {
+            return None;
+        }
+
+        row.checked_mul(stride)
+            .and_then(|start| start.checked_add(col))
+            .filter(|idx| *idx < bound)
+            .and_then(|idx| Self::try_from(idx).ok())
+    }
+
+    /// Parse a single digit character in the given `radix` (e.g. `'f'` in radix 16 -> `15`).
+    /// Returns `None` if `c` isn't a valid digit, or if its value is invalid for the type (e.g. `'0'` for
+    /// `NonZeroUsize`). Panics if `radix` is not in `2..=36`.
+    fn from_digit_char(c: char, radix: u32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        c.to_digit(radix)
+            .and_then(|digit| Self::try_from(digit as usize).ok())
+    }
+
+    /// Pack a string of digits in the given `radix` into a value, most significant digit first
+    /// (e.g. `"ff"` in radix 16 -> `255`). Returns `None` if `digits` is empty, has an invalid digit,
+    /// or its value exceeds max for the primitive. Panics if `radix` is not in `2..=36`.
+    fn from_digit_str(digits: &str, radix: u32) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        if digits.is_empty() {
+            return None;
+        }
+
+        digits
+            .chars()
+            .try_fold(0_usize, |acc, c| {
+                acc.checked_mul(radix as usize)?
+                    .checked_add(c.to_digit(radix)? as usize)
+            })
+            .and_then(|num| Self::try_from(num).ok())
+    }
+
+    /// Every value in `0..=max`, in order (e.g. to iterate the full logical domain of a bounded field).
+    /// Safe at `max == Self::MAX` (and `usize::MAX`), the iterator ends instead of wrapping.
+    /// Panics, up front, if `max` exceeds max for the primitive.
+    fn domain(max: usize) -> impl Iterator<Item = Self>
+    where
+        Self: Sized,
+    {
+        assert!(max <= Self::MAX);
+        (0..=max).map(Self::checked_from)
+    }
+
+    /// **Downcast:** Convert input `usize` (via `checked_from`) and fill an array with it.
+    /// Panics if `usize` exceeds max for returned unsigned primitive.
+    fn splat<const N: usize>(num: usize) -> [Self; N]
+    where
+        Self: Sized + Copy,
+    {
+        [Self::checked_from(num); N]
+    }
+
+    /// Bits `lo..hi` of value (least significant is bit `0`), shifted down to start at bit `0`
+    /// (e.g. `0b1011_0100.bit_range(2, 6)` -> `0b1101`). Panics unless `lo <= hi <= Self::BITS`.
+    fn bit_range(&self, lo: u32, hi: u32) -> usize {
+        assert!((lo <= hi) && (hi <= Self::BITS));
+
+        let width = hi - lo;
+        if width == 0 {
+            return 0;
+        }
+
+        (self.usize() >> lo) & (usize::MAX >> (usize::BITS - width))
+    }
+
+    /// Reverse the bit order of the underlying primitive, at its full width (e.g. `1_u8` -> `0x80`).
+    /// On concrete primitives, method syntax picks the inherent `reverse_bits` (same result).
+    fn reverse_bits(&self) -> Self
+    where
+        Self: Sized,
+    {
+        Self::checked_from(self.usize().reverse_bits() >> (usize::BITS - Self::BITS))
+    }
+
+    /// **Upcast:** Widen every element of `src` into `dst`. Panics if lengths differ.
+    /// Lengths are checked once up front, so the loop body is bounds-check free and can be autovectorized.
+    fn widen_slice_into(src: &[Self], dst: &mut [usize])
+    where
+        Self: Sized,
+    {
+        assert_eq!(src.len(), dst.len());
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = s.usize();
+        }
+    }
+
+    /// **Downcast:** Narrow every element of `src` into `dst`, or return the index of the first element that exceeds
+    /// max for the primitive (leaving `dst` untouched). Panics if lengths differ.
+    /// Overflow is detected with a branch-free reduction over all of `src`, which can be autovectorized,
+    /// and only located if present.
+    fn narrow_checked(src: &[usize], dst: &mut [Self]) -> Result<(), usize>
+    where
+        Self: Sized,
+    {
+        assert_eq!(src.len(), dst.len());
+
+        let max = Self::MAX;
+        let overflow = src.iter().fold(false, |acc, num| acc | (*num > max));
+        if overflow {
+            return Err(src.iter().position(|num| *num > max).unwrap_or_default());
+        }
+
+        // Already validated, masking is a no-op that lets the compiler drop `checked_from`'s assert
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = Self::checked_from(*s & max);
+        }
+
+        Ok(())
+    }
+
+    /// **Upcast:** Widen to an [`Index`], usable only for slice access.
+    fn as_index(&self) -> Index {
+        Index::new(self.usize())
+    }
+
+    /// Value as a concrete `u8`, for APIs that require exactly that primitive.
+    /// Returns `None` if the underlying primitive is wider than `u8`, even if this particular value would fit.
+    fn as_u8(&self) -> Option<u8> {
+        if Self::BITS <= u8::BITS {
+            Some(self.usize() as u8)
+        } else {
+            None
+        }
+    }
+
+    /// Value as a concrete `u16`, for APIs that require exactly that primitive.
+    /// Returns `None` if the underlying primitive is wider than `u16`, even if this particular value would fit.
+    fn as_u16(&self) -> Option<u16> {
+        if Self::BITS <= u16::BITS {
+            Some(self.usize() as u16)
+        } else {
+            None
+        }
+    }
+
+    /// Value as a concrete `u32`, for APIs that require exactly that primitive.
+    /// Returns `None` if the underlying primitive is wider than `u32`, even if this particular value would fit.
+    fn as_u32(&self) -> Option<u32> {
+        if Self::BITS <= u32::BITS {
+            Some(self.usize() as u32)
+        } else {
+            None
+        }
+    }
+
+    /// Value as a concrete `u64`, for APIs that require exactly that primitive.
+    /// Returns `None` if the underlying primitive is wider than `u64`, even if this particular value would fit.
+    fn as_u64(&self) -> Option<u64> {
+        if Self::BITS <= u64::BITS {
+            Some(self.usize() as u64)
+        } else {
+            None
+        }
+    }
+
+    /// Value as a concrete `u128`, for APIs that require exactly that primitive.
+    /// Returns `None` if the underlying primitive is wider than `u128`, even if this particular value would fit.
+    fn as_u128(&self) -> Option<u128> {
+        if Self::BITS <= u128::BITS {
+            Some(self.usize() as u128)
+        } else {
+            None
+        }
+    }
+}
+
+// Whether `num` fits an unsigned primitive of `bits` width, e.g. `usize` on a host of that width.
+// Parameterized so the narrower-host path is testable on any host.
+fn u64_fits_width(num: u64, bits: u32) -> bool {
+    (num as u128) <= (u128::MAX >> (u128::BITS - bits))
+}
+
+// Fill `bits` with the bits of `num`, least significant first.
+fn fill_bits(num: usize, bits: &mut [bool]) {
+    for (i, bit) in bits.iter_mut().enumerate() {
+        *bit = ((num >> i) & 1) == 1;
+    }
 }
 
 impl SmallUnsigned for usize {
@@ -70,9 +775,53 @@ impl SmallUnsigned for usize {
         *self
     }
 
+    #[inline]
     fn checked_from(num: usize) -> usize {
         num
     }
+
+    const BITS: u32 = usize::BITS;
+
+    fn label(&self) -> SmallUnsignedLabel {
+        SmallUnsignedLabel::USIZE
+    }
+
+    type BitArray = [bool; usize::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; usize::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
+}
+
+impl SmallUnsigned for NonZeroUsize {
+    fn usize(&self) -> usize {
+        self.get()
+    }
+
+    fn checked_from(num: usize) -> NonZeroUsize {
+        assert!(num >= 1);
+        NonZeroUsize::new(num).unwrap()
+    }
+
+    fn try_from(num: usize) -> Result<NonZeroUsize, SmallNumError> {
+        NonZeroUsize::new(num).ok_or(SmallNumError::Zero)
+    }
+
+    const BITS: u32 = usize::BITS;
+
+    fn label(&self) -> SmallUnsignedLabel {
+        SmallUnsignedLabel::USIZE
+    }
+
+    type BitArray = [bool; usize::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; usize::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
 }
 
 impl SmallUnsigned for u8 {
@@ -80,10 +829,21 @@ impl SmallUnsigned for u8 {
         *self as usize
     }
 
+    #[inline]
     fn checked_from(num: usize) -> u8 {
         assert!(num <= u8::MAX as usize);
         num as u8
     }
+
+    const BITS: u32 = u8::BITS;
+
+    type BitArray = [bool; u8::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; u8::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
 }
 
 #[cfg(any(
@@ -97,10 +857,21 @@ impl SmallUnsigned for u16 {
         *self as usize
     }
 
+    #[inline]
     fn checked_from(num: usize) -> u16 {
         assert!(num <= u16::MAX as usize);
         num as u16
     }
+
+    const BITS: u32 = u16::BITS;
+
+    type BitArray = [bool; u16::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; u16::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
 }
 
 #[cfg(any(
@@ -113,10 +884,21 @@ impl SmallUnsigned for u32 {
         *self as usize
     }
 
+    #[inline]
     fn checked_from(num: usize) -> u32 {
         assert!(num <= u32::MAX as usize);
         num as u32
     }
+
+    const BITS: u32 = u32::BITS;
+
+    type BitArray = [bool; u32::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; u32::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
 }
 
 #[cfg(any(target_pointer_width = "64", target_pointer_width = "128",))]
@@ -125,10 +907,21 @@ impl SmallUnsigned for u64 {
         *self as usize
     }
 
+    #[inline]
     fn checked_from(num: usize) -> u64 {
         assert!(num <= u64::MAX as usize);
         num as u64
     }
+
+    const BITS: u32 = u64::BITS;
+
+    type BitArray = [bool; u64::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; u64::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
 }
 
 #[cfg(target_pointer_width = "128")]
@@ -137,23 +930,188 @@ impl SmallUnsigned for u128 {
         *self as usize
     }
 
+    #[inline]
     fn checked_from(num: usize) -> u128 {
         assert!(num <= u128::MAX as usize);
         num as u128
     }
+
+    const BITS: u32 = u128::BITS;
+
+    type BitArray = [bool; u128::BITS as usize];
+
+    fn to_bits(&self) -> Self::BitArray {
+        let mut bits = [false; u128::BITS as usize];
+        fill_bits(self.usize(), &mut bits);
+        bits
+    }
 }
 
-// Compile-time Type Mapping -------------------------------------------------------------------------------------------
+/// Forwards to the wrapped primitive.
+/// References need no impl of their own: methods take `&self` (e.g. `slice.iter().map(SmallUnsigned::usize)`).
+impl<T: SmallUnsigned> SmallUnsigned for Wrapping<T> {
+    fn usize(&self) -> usize {
+        self.0.usize()
+    }
 
-/// Return smallest unsigned type capable of representing input value (positive, i.e. maximum).
-///
-/// # Example
-///
-/// ```
-/// use smallnum::{small_unsigned, SmallUnsigned};
-/// use core::mem::size_of_val;
-///
-/// let idx: usize = 5;
+    fn checked_from(num: usize) -> Wrapping<T> {
+        Wrapping(T::checked_from(num))
+    }
+
+    const BITS: u32 = T::BITS;
+
+    fn label(&self) -> SmallUnsignedLabel {
+        self.0.label()
+    }
+
+    type BitArray = T::BitArray;
+
+    fn to_bits(&self) -> Self::BitArray {
+        self.0.to_bits()
+    }
+}
+
+/// Forwards to the wrapped primitive, ordering stays reversed (e.g. for min-heaps built on `BinaryHeap`).
+impl<T: SmallUnsigned> SmallUnsigned for Reverse<T> {
+    fn usize(&self) -> usize {
+        self.0.usize()
+    }
+
+    fn checked_from(num: usize) -> Reverse<T> {
+        Reverse(T::checked_from(num))
+    }
+
+    const BITS: u32 = T::BITS;
+
+    fn label(&self) -> SmallUnsignedLabel {
+        self.0.label()
+    }
+
+    type BitArray = T::BitArray;
+
+    fn to_bits(&self) -> Self::BitArray {
+        self.0.to_bits()
+    }
+}
+
+// Array Extension -----------------------------------------------------------------------------------------------------
+
+/// Convenience trait for multi-dimensional indexes stored as arrays of small unsigned components.
+pub trait SmallUnsignedArray<const D: usize> {
+    /// **Upcast:** Get every component as host register-width unsigned (e.g. `[u8; 3]` -> `[usize; 3]`).
+    fn widen_all(&self) -> [usize; D];
+}
+
+impl<T: SmallUnsigned, const D: usize> SmallUnsignedArray<D> for [T; D] {
+    fn widen_all(&self) -> [usize; D] {
+        core::array::from_fn(|i| self[i].usize())
+    }
+}
+
+// Bulk Conversion -----------------------------------------------------------------------------------------------------
+
+/// Narrow every `usize` in `src` into the same position of `dst`, without allocating.
+/// On error, `dst` is left untouched.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{narrow_into, small_unsigned, SmallNumError, SmallUnsignedLabel};
+///
+/// let src: [usize; 3] = [1, 2, 300];
+/// let mut dst: [small_unsigned!(500); 3] = [0; 3];
+///
+/// assert!(narrow_into(&src, &mut dst).is_ok());
+/// assert_eq!(dst, [1, 2, 300]);
+///
+/// let mut too_small: [small_unsigned!(200); 3] = [0; 3];
+/// assert_eq!(
+///     narrow_into(&src, &mut too_small),
+///     Err(SmallNumError::Overflow { value: 300, max: 255, label: SmallUnsignedLabel::U8 })
+/// );
+/// ```
+pub fn narrow_into<T: SmallUnsigned>(src: &[usize], dst: &mut [T]) -> Result<(), SmallNumError> {
+    if src.len() != dst.len() {
+        return Err(SmallNumError::LengthMismatch {
+            src_len: src.len(),
+            dst_len: dst.len(),
+        });
+    }
+
+    let max = T::MAX;
+    if let Some(value) = src.iter().find(|num| **num > max) {
+        return Err(SmallNumError::overflow(*value, max));
+    }
+
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = T::checked_from(*s);
+    }
+
+    Ok(())
+}
+
+// Iteration -----------------------------------------------------------------------------------------------------------
+
+/// Convenience for scanning a bounded index range: calls `f` with each index in `0..=max`, in order.
+/// Indexes are passed as `usize`, no intermediate small values are constructed.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::for_each_index;
+///
+/// let mut sum = 0;
+/// for_each_index(4, |i| sum += i);
+///
+/// assert_eq!(sum, 1 + 2 + 3 + 4);
+/// ```
+pub fn for_each_index<F: FnMut(usize)>(max: usize, f: F) {
+    (0..=max).for_each(f);
+}
+
+/// Bit-reversal permutation of `0..(1 << n_bits)` (e.g. the input reordering of a radix-2 FFT), where entry `i`
+/// is `i` with its low `n_bits` reversed.
+/// Panics if `n_bits` exceeds `T::BITS`, if `n_bits` isn't below `usize::BITS` (the permutation has `1 << n_bits`
+/// entries), or if `T` can't hold `0` (e.g. `NonZeroUsize`).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::bit_reverse_perm;
+///
+/// let perm = bit_reverse_perm::<u8>(3);
+/// assert_eq!(perm, vec![0, 4, 2, 6, 1, 5, 3, 7]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn bit_reverse_perm<T: SmallUnsigned>(n_bits: u32) -> Vec<T> {
+    assert!(
+        n_bits <= T::BITS,
+        "bit_reverse_perm: n_bits exceeds T::BITS"
+    );
+    assert!(
+        n_bits < usize::BITS,
+        "bit_reverse_perm: 1 << n_bits overflows usize"
+    );
+
+    (0..(1_usize << n_bits))
+        .map(|i| {
+            let rev = T::checked_from(i).reverse_bits().usize();
+            T::checked_from(rev.checked_shr(T::BITS - n_bits).unwrap_or(0))
+        })
+        .collect()
+}
+
+// Compile-time Type Mapping -------------------------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing input value (positive, i.e. maximum).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::{small_unsigned, SmallUnsigned};
+/// use core::mem::size_of_val;
+///
+/// let idx: usize = 5;
 /// let small_idx: small_unsigned!(500) = 5;
 ///
 /// assert_eq!(idx, small_idx.usize());
@@ -163,11 +1121,11 @@ impl SmallUnsigned for u128 {
 macro_rules! small_unsigned {
     ( $max:expr $(,)? ) => {
         <() as $crate::ShrinkUnsigned<
-            { ($max as u128) <= (core::u8::MAX as u128) },
-            { ($max as u128) <= (core::u16::MAX as u128) },
-            { ($max as u128) <= (core::u32::MAX as u128) },
-            { ($max as u128) <= (core::u64::MAX as u128) },
-            { ($max as u128) <= (core::u128::MAX as u128) },
+            { ($max as u128) <= (u8::MAX as u128) },
+            { ($max as u128) <= (u16::MAX as u128) },
+            { ($max as u128) <= (u32::MAX as u128) },
+            { ($max as u128) <= (u64::MAX as u128) },
+            { ($max as u128) <= (u128::MAX as u128) },
         >>::UnsignedType
     };
 }
@@ -206,6 +1164,355 @@ impl ShrinkUnsigned<false, false, false, false, true> for () {
     type UnsignedType = u128;
 }
 
+// Compile-time Type Mapping (From Range) -----------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing any offset into a constant, half-open range
+/// (e.g. `0..500`).
+///
+/// Note the **length vs. maximum** distinction: the selected type fits offsets `0..(end - start)`,
+/// so the largest value it's required to hold is `end - start - 1`.
+/// Hence `small_unsigned_for_range!(0..256)` is `u8`, whereas `small_unsigned!(256)` is `u16`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_for_range;
+/// use core::mem::size_of;
+///
+/// const LEN: usize = 256;
+/// let buf = [0xff_u8; LEN];
+/// let idx: small_unsigned_for_range!(0..LEN) = 255;
+///
+/// assert_eq!(buf[idx as usize], 0xff);
+/// assert_eq!(size_of::<small_unsigned_for_range!(0..LEN)>(), 1);
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_for_range {
+    ( $range:expr $(,)? ) => {
+        $crate::small_unsigned!((($range).end as u128 - ($range).start as u128).saturating_sub(1))
+    };
+}
+
+// Compile-time Type Mapping (With Headroom) --------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing input value (positive, i.e. maximum) plus a percentage of
+/// headroom for growth, i.e. `max + (max * percent / 100)`.
+/// Fails to compile if computing the padded maximum overflows `u128`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_headroom;
+/// use core::mem::size_of;
+///
+/// const MAX_USERS: usize = 200;
+///
+/// // 200 + 50% = 300
+/// let users: small_unsigned_headroom!(MAX_USERS, 50) = 300;
+///
+/// assert_eq!(users, 300);
+/// assert_eq!(size_of::<small_unsigned_headroom!(MAX_USERS, 50)>(), 2);
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_headroom {
+    ( $max:expr, $percent:expr $(,)? ) => {
+        // Overflow is only reported by the first predicate, so the user sees a single error
+        <() as $crate::ShrinkUnsigned<
+            {
+                match $crate::headroom_max($max as u128, $percent as u128) {
+                    Some(padded) => padded <= (u8::MAX as u128),
+                    None => panic!("small_unsigned_headroom!: max plus headroom overflows u128"),
+                }
+            },
+            {
+                match $crate::headroom_max($max as u128, $percent as u128) {
+                    Some(padded) => padded <= (u16::MAX as u128),
+                    None => false,
+                }
+            },
+            {
+                match $crate::headroom_max($max as u128, $percent as u128) {
+                    Some(padded) => padded <= (u32::MAX as u128),
+                    None => false,
+                }
+            },
+            {
+                match $crate::headroom_max($max as u128, $percent as u128) {
+                    Some(padded) => padded <= (u64::MAX as u128),
+                    None => false,
+                }
+            },
+            { $crate::headroom_max($max as u128, $percent as u128).is_some() },
+        >>::UnsignedType
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper for headroom type mapping, `None` if the padded maximum overflows. Internal use only.
+pub const fn headroom_max(max: u128, percent: u128) -> Option<u128> {
+    match max.checked_mul(percent) {
+        Some(scaled) => max.checked_add(scaled / 100),
+        None => None,
+    }
+}
+
+// Compile-time Type Mapping (From Sum) -------------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing the sum of input values (positive, i.e. maximums),
+/// e.g. for a single index space shared by several bounded collections.
+/// Fails to compile if the sum overflows `u128`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_sum;
+/// use core::mem::size_of;
+///
+/// const MAX_NODES: usize = 200;
+/// const MAX_EDGES: usize = 100;
+///
+/// // 200 + 100 = 300
+/// let id: small_unsigned_sum!(MAX_NODES, MAX_EDGES) = 300;
+///
+/// assert_eq!(id, 300);
+/// assert_eq!(size_of::<small_unsigned_sum!(MAX_NODES, MAX_EDGES)>(), 2);
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_sum {
+    ( $( $max:expr ),+ $(,)? ) => {
+        // Overflow is only reported by the first predicate, so the user sees a single error
+        <() as $crate::ShrinkUnsigned<
+            {
+                match $crate::sum_max(&[$( $max as u128 ),+]) {
+                    Some(sum) => sum <= (u8::MAX as u128),
+                    None => panic!("small_unsigned_sum!: sum overflows u128"),
+                }
+            },
+            {
+                match $crate::sum_max(&[$( $max as u128 ),+]) {
+                    Some(sum) => sum <= (u16::MAX as u128),
+                    None => false,
+                }
+            },
+            {
+                match $crate::sum_max(&[$( $max as u128 ),+]) {
+                    Some(sum) => sum <= (u32::MAX as u128),
+                    None => false,
+                }
+            },
+            {
+                match $crate::sum_max(&[$( $max as u128 ),+]) {
+                    Some(sum) => sum <= (u64::MAX as u128),
+                    None => false,
+                }
+            },
+            { $crate::sum_max(&[$( $max as u128 ),+]).is_some() },
+        >>::UnsignedType
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper for sum type mapping, `None` if the sum overflows. Internal use only.
+pub const fn sum_max(maxes: &[u128]) -> Option<u128> {
+    let mut sum: u128 = 0;
+    let mut i = 0;
+    while i < maxes.len() {
+        sum = match sum.checked_add(maxes[i]) {
+            Some(sum) => sum,
+            None => return None,
+        };
+        i += 1;
+    }
+
+    Some(sum)
+}
+
+// Compile-time Type Mapping (By Bit Width) ---------------------------------------------------------------------------
+
+/// Return smallest unsigned type with at least `n` value bits, i.e. capable of representing `(1 << n) - 1`
+/// (e.g. a 12-bit hardware register value).
+/// Fails to compile if `n` exceeds `128`.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_bits;
+/// use core::mem::size_of;
+///
+/// let reg: small_unsigned_bits!(12) = 0xfff;
+///
+/// assert_eq!(reg, 4_095);
+/// assert_eq!(size_of::<small_unsigned_bits!(12)>(), 2);
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_bits {
+    ( $n:expr $(,)? ) => {
+        // Past 128 bits, no predicate holds and no type is selected
+        <() as $crate::ShrinkUnsigned<
+            { (($n as u32) <= 128) && ($crate::bits_max($n as u32) <= (u8::MAX as u128)) },
+            { (($n as u32) <= 128) && ($crate::bits_max($n as u32) <= (u16::MAX as u128)) },
+            { (($n as u32) <= 128) && ($crate::bits_max($n as u32) <= (u32::MAX as u128)) },
+            { (($n as u32) <= 128) && ($crate::bits_max($n as u32) <= (u64::MAX as u128)) },
+            { ($n as u32) <= 128 },
+        >>::UnsignedType
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper for bit width type mapping, `(1 << n) - 1` saturating at `u128::MAX`. Internal use only.
+pub const fn bits_max(n: u32) -> u128 {
+    if n >= u128::BITS {
+        u128::MAX
+    } else {
+        (1 << n) - 1
+    }
+}
+
+// Compile-time Type Mapping (Power of Two) ---------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing any index into a power-of-two capacity `2^exp`
+/// (e.g. a ring buffer masked by `(1 << exp) - 1`), i.e. capable of representing `(1 << exp) - 1`.
+/// Fails to compile if `exp` is `128` or more.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_pow2;
+/// use core::mem::size_of;
+///
+/// const CAPACITY_EXP: u32 = 10;
+///
+/// let head: small_unsigned_pow2!(CAPACITY_EXP) = 1_023;
+///
+/// assert_eq!(head, (1 << CAPACITY_EXP) - 1);
+/// assert_eq!(size_of::<small_unsigned_pow2!(8)>(), 1);
+/// assert_eq!(size_of::<small_unsigned_pow2!(CAPACITY_EXP)>(), 2);
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_pow2 {
+    ( $exp:expr $(,)? ) => {
+        // Too wide an exponent is only reported by the first predicate, so the user sees a single error
+        <() as $crate::ShrinkUnsigned<
+            {
+                if ($exp as u32) >= 128 {
+                    panic!("small_unsigned_pow2!: exp must be below 128");
+                }
+                $crate::bits_max($exp as u32) <= (u8::MAX as u128)
+            },
+            { (($exp as u32) < 128) && ($crate::bits_max($exp as u32) <= (u16::MAX as u128)) },
+            { (($exp as u32) < 128) && ($crate::bits_max($exp as u32) <= (u32::MAX as u128)) },
+            { (($exp as u32) < 128) && ($crate::bits_max($exp as u32) <= (u64::MAX as u128)) },
+            { ($exp as u32) < 128 },
+        >>::UnsignedType
+    };
+}
+
+// Compile-time Type Mapping (ISA Floor) ------------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing input value (positive, i.e. maximum), but no smaller than
+/// the narrowest width the given architecture operates on efficiently.
+///
+/// Supported arch hints:
+///
+/// * `"riscv32"`, `"riscv64"`: at least `u32`. The RV32I/RV64I base ISAs (RISC-V Unprivileged ISA spec, chapters
+///   "RV32I Base Integer Instruction Set" and "RV64I Base Integer Instruction Set") only load and store bytes and
+///   halfwords, all arithmetic is register-width, so sub-word values need extra instructions to re-truncate.
+///   RV64I adds 32-bit `*W` forms (e.g. `ADDW`), so 32-bit arithmetic stays a single instruction.
+/// * `"x86"`, `"x86_64"`, `"arm"`, `"aarch64"`: no floor, same as [`small_unsigned!`](crate::small_unsigned).
+///
+/// Any other hint fails to compile.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_isa;
+/// use core::mem::size_of;
+///
+/// assert_eq!(size_of::<small_unsigned_isa!(100, arch = "riscv32")>(), 4);
+/// assert_eq!(size_of::<small_unsigned_isa!(100, arch = "x86_64")>(), 1);
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_isa {
+    ( $max:expr, arch = "riscv32" $(,)? ) => {
+        $crate::small_unsigned!($crate::isa_floor($max as u128, u32::MAX as u128))
+    };
+    ( $max:expr, arch = "riscv64" $(,)? ) => {
+        $crate::small_unsigned!($crate::isa_floor($max as u128, u32::MAX as u128))
+    };
+    ( $max:expr, arch = "x86" $(,)? ) => {
+        $crate::small_unsigned!($max)
+    };
+    ( $max:expr, arch = "x86_64" $(,)? ) => {
+        $crate::small_unsigned!($max)
+    };
+    ( $max:expr, arch = "arm" $(,)? ) => {
+        $crate::small_unsigned!($max)
+    };
+    ( $max:expr, arch = "aarch64" $(,)? ) => {
+        $crate::small_unsigned!($max)
+    };
+    // In a const expression, not type position, so the error doesn't also surface as a mismatched `()` type
+    ( $max:expr, arch = $arch:literal $(,)? ) => {
+        $crate::small_unsigned!(compile_error!(concat!(
+            "small_unsigned_isa!: unsupported arch hint ",
+            stringify!($arch)
+        )))
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper for ISA type mapping, raises `max` to the floor type's max. Internal use only.
+pub const fn isa_floor(max: u128, floor_max: u128) -> u128 {
+    if max > floor_max {
+        max
+    } else {
+        floor_max
+    }
+}
+
+// Compile-time Type Mapping (With Fallback) --------------------------------------------------------------------------
+
+/// Return smallest unsigned type capable of representing input value (positive, i.e. maximum),
+/// unless that type is wider than the host's `usize`, in which case return the fallback type.
+///
+/// # Example
+///
+/// ```
+/// use smallnum::small_unsigned_or;
+/// use core::mem::size_of;
+///
+/// type Fits = small_unsigned_or!(500, usize);
+/// type TooWide = small_unsigned_or!(u128::MAX, usize);
+///
+/// assert_eq!(size_of::<Fits>(), 2);
+/// assert_eq!(size_of::<TooWide>(), size_of::<usize>());
+/// ```
+#[macro_export]
+macro_rules! small_unsigned_or {
+    ( $max:expr, $fallback:ty $(,)? ) => {
+        <() as $crate::ShrinkUnsignedOr<
+            { ($max as u128) <= (usize::MAX as u128) },
+            $crate::small_unsigned!($max),
+            $fallback,
+        >>::UnsignedType
+    };
+}
+
+#[doc(hidden)] // API user should never have to be aware this exists.
+/// Helper trait for unsigned type mapping with a fallback. Internal use only.
+pub trait ShrinkUnsignedOr<const FITS_HOST: bool, Small, Fallback> {
+    /// Smallest primitive type that can represent a bounded unsigned value, if no wider than the host
+    type UnsignedType;
+}
+
+impl<Small, Fallback> ShrinkUnsignedOr<true, Small, Fallback> for () {
+    type UnsignedType = Small;
+}
+
+impl<Small, Fallback> ShrinkUnsignedOr<false, Small, Fallback> for () {
+    type UnsignedType = Fallback;
+}
+
 // Compile-time Label Mapping ------------------------------------------------------------------------------------------
 
 /// Return a label corresponding to the smallest type capable of representing input value
@@ -225,7 +1532,48 @@ impl ShrinkUnsigned<false, false, false, false, true> for () {
 #[macro_export]
 macro_rules! small_unsigned_label {
     ( $max:expr $(,)? ) => {
-        SmallUnsignedLabel::new($max)
+        $crate::SmallUnsignedLabel::new($max)
+    };
+}
+
+// Compile-time Layout Verification -----------------------------------------------------------------------------------
+
+/// Lock the width `small_unsigned!` selects for each named bound in one place, failing the build on any mismatch
+/// (e.g. a CI guard for serialized or `repr(C)` layouts).
+/// Each bound must be a single token, parenthesize anything longer (e.g. `max (u16::MAX)`).
+///
+/// # Example
+///
+/// ```
+/// use smallnum::smallnum_verify;
+///
+/// const MAX_GEN: usize = 3;
+///
+/// smallnum_verify! {
+///     field Idx: max 500 == 2 bytes;
+///     field Gen: max MAX_GEN == 1 byte;
+///     field Hash: max (u32::MAX) == 4 bytes;
+/// }
+/// ```
+#[macro_export]
+macro_rules! smallnum_verify {
+    () => {};
+    ( field $name:ident : max $max:tt == $bytes:literal byte ; $( $rest:tt )* ) => {
+        $crate::smallnum_verify!(@assert $name, $max, $bytes, "byte");
+        $crate::smallnum_verify!($( $rest )*);
+    };
+    ( field $name:ident : max $max:tt == $bytes:literal bytes ; $( $rest:tt )* ) => {
+        $crate::smallnum_verify!(@assert $name, $max, $bytes, "bytes");
+        $crate::smallnum_verify!($( $rest )*);
+    };
+    ( @assert $name:ident, $max:tt, $bytes:literal, $unit:literal ) => {
+        const _: () = assert!(
+            ::core::mem::size_of::<$crate::small_unsigned!($max)>() == $bytes,
+            concat!(
+                "smallnum_verify!: `", stringify!($name), "` (max ", stringify!($max), ") is not ",
+                stringify!($bytes), " ", $unit
+            )
+        );
     };
 }
 
@@ -234,8 +1582,16 @@ macro_rules! small_unsigned_label {
 #[cfg(test)]
 mod tests {
 
-    use crate::{SmallUnsigned, SmallUnsignedLabel};
+    use crate::{
+        assert_selected_bytes, fingerprint, for_each_index, label_for_distinct_values, narrow_into,
+        selection_report, small_unsigned_fits, wasted_headroom, SmallNumError, SmallUnsigned,
+        SmallUnsignedArray, SmallUnsignedLabel,
+    };
+    use core::alloc::Layout;
+    use core::cmp::Reverse;
     use core::mem::size_of;
+    use core::num::{NonZeroUsize, Wrapping};
+    use static_assertions::assert_type_eq_all;
 
     const MAX_VAL_UNSIGNED: usize = 512;
 
@@ -279,26 +1635,23 @@ mod tests {
         #[cfg(target_pointer_width = "128")]
         let u128_num: U128Type = 18_500_000_000_000_000_000;
 
-        assert_eq!(u8_num.usize(), 200 as usize);
-        assert_eq!(u16_num.usize(), 500 as usize);
-        assert_eq!(u32_num.usize(), 100_000 as usize);
+        assert_eq!(u8_num.usize(), 200_usize);
+        assert_eq!(u16_num.usize(), 500_usize);
+        assert_eq!(u32_num.usize(), 100_000_usize);
 
         #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
-        assert_eq!(u64_num.usize(), 4_300_000_000 as usize);
+        assert_eq!(u64_num.usize(), 4_300_000_000_usize);
 
         #[cfg(target_pointer_width = "128")]
         assert_eq!(u128_num.usize(), 18_500_000_000_000_000_000 as usize);
 
         // Normalization Check (from usize) ----------------------------------------------------------------------------
 
-        assert_eq!(200 as u8, u8::checked_from(200 as usize));
-        assert_eq!(500 as u16, u16::checked_from(500 as usize));
+        assert_eq!(200_u8, u8::checked_from(200_usize));
+        assert_eq!(500_u16, u16::checked_from(500_usize));
 
         #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
-        assert_eq!(
-            4_300_000_000 as u64,
-            u64::checked_from(4_300_000_000 as usize)
-        );
+        assert_eq!(4_300_000_000_u64, u64::checked_from(4_300_000_000_usize));
 
         #[cfg(target_pointer_width = "128")]
         assert_eq!(
@@ -307,6 +1660,161 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unsigned_range_macro() {
+        // Length 256 -> max offset 255
+        type U8Type = small_unsigned_for_range!(0..256);
+        type U8TypeOffset = small_unsigned_for_range!(1_000..1_256);
+        type U16Type = small_unsigned_for_range!(0..257);
+        type U16TypeMax = small_unsigned_for_range!(0..MAX_VAL_UNSIGNED);
+        type U8TypeEmpty = small_unsigned_for_range!(0..0);
+
+        assert_type_eq_all!(U8Type, U8TypeOffset, U8TypeEmpty, u8);
+        assert_type_eq_all!(U16Type, U16TypeMax, u16);
+
+        // Bound is a length, not a max
+        assert_type_eq_all!(small_unsigned!(256), u16);
+    }
+
+    #[test]
+    fn unsigned_bits_macro() {
+        const REG_BITS: u32 = 12;
+
+        assert_type_eq_all!(small_unsigned_bits!(0), u8);
+        assert_type_eq_all!(small_unsigned_bits!(1), u8);
+        assert_type_eq_all!(small_unsigned_bits!(8), u8);
+        assert_type_eq_all!(small_unsigned_bits!(9), u16);
+        assert_type_eq_all!(small_unsigned_bits!(REG_BITS), u16);
+        assert_type_eq_all!(small_unsigned_bits!(16), u16);
+        assert_type_eq_all!(small_unsigned_bits!(17), u32);
+        assert_type_eq_all!(small_unsigned_bits!(32), u32);
+        assert_type_eq_all!(small_unsigned_bits!(33), u64);
+        assert_type_eq_all!(small_unsigned_bits!(64), u64);
+        assert_type_eq_all!(small_unsigned_bits!(65), u128);
+        assert_type_eq_all!(small_unsigned_bits!(128), u128);
+
+        // Same selection as the equivalent max
+        assert_type_eq_all!(small_unsigned_bits!(20), small_unsigned!((1 << 20) - 1));
+    }
+
+    #[test]
+    fn unsigned_headroom_macro() {
+        type U16Type = small_unsigned_headroom!(200, 50);
+        type U8Type = small_unsigned_headroom!(170, 50);
+        type U8TypeEdge = small_unsigned_headroom!(255, 0);
+        type U16TypeEdge = small_unsigned_headroom!(171, 50);
+        type U32Type = small_unsigned_headroom!(u16::MAX, 1);
+
+        assert_type_eq_all!(U16Type, u16);
+        assert_type_eq_all!(U8Type, u8);
+        assert_type_eq_all!(U8TypeEdge, u8);
+        assert_type_eq_all!(U16TypeEdge, u16);
+        assert_type_eq_all!(U32Type, u32);
+
+        assert_eq!(crate::headroom_max(200, 50), Some(300));
+        assert_eq!(crate::headroom_max(u128::MAX, 0), Some(u128::MAX));
+        assert_eq!(crate::headroom_max(u128::MAX, 1), None);
+        assert_eq!(crate::headroom_max(u128::MAX, 200), None);
+    }
+
+    #[test]
+    fn unsigned_macro_self_bound() {
+        struct Ring {
+            head: small_unsigned!(Ring::CAP),
+        }
+
+        impl Ring {
+            const CAP: usize = 300;
+
+            fn advance(&mut self) -> usize {
+                let next: small_unsigned!(Self::CAP) = self.head + 1;
+                self.head = next;
+                self.head.usize()
+            }
+        }
+
+        let mut ring = Ring { head: 7 };
+        assert_eq!(ring.advance(), 8);
+        assert_eq!(size_of::<Ring>(), 2);
+    }
+
+    #[test]
+    fn unsigned_sum_macro() {
+        const MAX_NODES: usize = 200;
+        const MAX_EDGES: usize = 100;
+
+        assert_type_eq_all!(small_unsigned_sum!(200, 100), u16);
+        assert_type_eq_all!(small_unsigned_sum!(MAX_NODES, MAX_EDGES), u16);
+        assert_type_eq_all!(small_unsigned_sum!(200, 55), u8);
+        assert_type_eq_all!(small_unsigned_sum!(255), u8);
+        assert_type_eq_all!(small_unsigned_sum!(100, 100, 56,), u16);
+        assert_type_eq_all!(small_unsigned_sum!(u32::MAX, 0), u32);
+        assert_type_eq_all!(small_unsigned_sum!(u32::MAX, 1), u64);
+        assert_type_eq_all!(small_unsigned_sum!(u128::MAX - 1, 1), u128);
+
+        assert_eq!(crate::sum_max(&[]), Some(0));
+        assert_eq!(crate::sum_max(&[u128::MAX, 1]), None);
+    }
+
+    #[test]
+    fn unsigned_pow2_macro() {
+        const RING_EXP: u32 = 10;
+
+        assert_type_eq_all!(small_unsigned_pow2!(0), u8);
+        assert_type_eq_all!(small_unsigned_pow2!(8), u8);
+        assert_type_eq_all!(small_unsigned_pow2!(9), u16);
+        assert_type_eq_all!(small_unsigned_pow2!(RING_EXP), u16);
+        assert_type_eq_all!(small_unsigned_pow2!(16), u16);
+        assert_type_eq_all!(small_unsigned_pow2!(17), u32);
+        assert_type_eq_all!(small_unsigned_pow2!(64), u64);
+        assert_type_eq_all!(small_unsigned_pow2!(127), u128);
+
+        // Max index, off-by-one vs. `small_unsigned!` on the capacity
+        assert_type_eq_all!(small_unsigned_pow2!(8), small_unsigned!(255));
+        assert_type_eq_all!(small_unsigned!(1 << 8), u16);
+    }
+
+    #[test]
+    fn unsigned_isa_macro() {
+        type RiscvSmall = small_unsigned_isa!(100, arch = "riscv32");
+        type RiscvMid = small_unsigned_isa!(MAX_VAL_UNSIGNED, arch = "riscv64");
+        type RiscvLarge = small_unsigned_isa!(u32::MAX as u128 + 1, arch = "riscv64");
+        type X86Small = small_unsigned_isa!(100, arch = "x86_64");
+        type ArmMid = small_unsigned_isa!(MAX_VAL_UNSIGNED, arch = "aarch64");
+
+        assert_type_eq_all!(RiscvSmall, u32);
+        assert_type_eq_all!(RiscvMid, u32);
+        assert_type_eq_all!(RiscvLarge, u64);
+        assert_type_eq_all!(X86Small, u8);
+        assert_type_eq_all!(ArmMid, u16);
+    }
+
+    #[test]
+    fn unsigned_or_macro() {
+        type U8Type = small_unsigned_or!(200, usize);
+        type U16Type = small_unsigned_or!(MAX_VAL_UNSIGNED, usize);
+        type Fallback = small_unsigned_or!(u128::MAX, usize);
+        type CustomFallback = small_unsigned_or!(u128::MAX, u64);
+
+        assert_type_eq_all!(U8Type, u8);
+        assert_type_eq_all!(U16Type, u16);
+        assert_type_eq_all!(Fallback, usize);
+        assert_type_eq_all!(CustomFallback, u64);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            type HostWidth = small_unsigned_or!(u64::MAX, usize);
+            assert_type_eq_all!(HostWidth, u64);
+        }
+
+        // A 64-bit bound on a 32-bit host must fall back
+        #[cfg(target_pointer_width = "32")]
+        {
+            type TooWide = small_unsigned_or!(1_u64 << 40, usize);
+            assert_type_eq_all!(TooWide, usize);
+        }
+    }
+
     #[test]
     fn unsigned_label_macro() {
         // Label mapping -----------------------------------------------------------------------------------------------
@@ -335,4 +1843,1392 @@ mod tests {
         #[cfg(target_pointer_width = "128")]
         assert_eq!(u128_label, SmallUnsignedLabel::U128);
     }
+
+    // No `SmallUnsignedLabel` in scope, the macro must resolve it itself
+    mod label_hygiene {
+        #[test]
+        fn unsigned_label_macro_hygiene() {
+            assert_eq!(
+                crate::small_unsigned_label!(300),
+                crate::SmallUnsignedLabel::U16
+            );
+        }
+    }
+
+    #[test]
+    fn unsigned_label_type_name() {
+        assert_eq!(SmallUnsignedLabel::USIZE.type_name(), "usize");
+        assert_eq!(SmallUnsignedLabel::U8.type_name(), "u8");
+        assert_eq!(SmallUnsignedLabel::U16.type_name(), "u16");
+        assert_eq!(SmallUnsignedLabel::U32.type_name(), "u32");
+        assert_eq!(SmallUnsignedLabel::U64.type_name(), "u64");
+        assert_eq!(SmallUnsignedLabel::U128.type_name(), "u128");
+    }
+
+    #[test]
+    fn unsigned_label_describe() {
+        assert_eq!(SmallUnsignedLabel::U8.describe(), "uint8");
+        assert_eq!(SmallUnsignedLabel::U16.describe(), "uint16");
+        assert_eq!(SmallUnsignedLabel::U32.describe(), "uint32");
+        assert_eq!(SmallUnsignedLabel::U64.describe(), "uint64");
+        assert_eq!(SmallUnsignedLabel::U128.describe(), "uint128");
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(SmallUnsignedLabel::USIZE.describe(), "uint64");
+
+        #[cfg(target_pointer_width = "32")]
+        assert_eq!(SmallUnsignedLabel::USIZE.describe(), "uint32");
+    }
+
+    #[test]
+    fn unsigned_label_for_distinct_values() {
+        assert_eq!(label_for_distinct_values(0), SmallUnsignedLabel::U8);
+        assert_eq!(label_for_distinct_values(1), SmallUnsignedLabel::U8);
+        assert_eq!(label_for_distinct_values(256), SmallUnsignedLabel::U8);
+        assert_eq!(label_for_distinct_values(257), SmallUnsignedLabel::U16);
+        assert_eq!(label_for_distinct_values(65_536), SmallUnsignedLabel::U16);
+        assert_eq!(label_for_distinct_values(65_537), SmallUnsignedLabel::U32);
+
+        const LABEL: SmallUnsignedLabel = label_for_distinct_values(usize::MAX);
+        assert!(LABEL.layout_eq(SmallUnsignedLabel::USIZE));
+    }
+
+    #[test]
+    fn unsigned_selection_report() {
+        let fits = |max: u128| {
+            let mut flags = [false; 5];
+            for (i, (_, fit)) in selection_report(max).iter().enumerate() {
+                flags[i] = *fit;
+            }
+            flags
+        };
+
+        assert_eq!(fits(0), [true; 5]);
+        assert_eq!(fits(255), [true; 5]);
+        assert_eq!(fits(256), [false, true, true, true, true]);
+        assert_eq!(fits(65_535), [false, true, true, true, true]);
+        assert_eq!(fits(65_536), [false, false, true, true, true]);
+        assert_eq!(
+            fits(u32::MAX as u128 + 1),
+            [false, false, false, true, true]
+        );
+        assert_eq!(
+            fits(u64::MAX as u128 + 1),
+            [false, false, false, false, true]
+        );
+        assert_eq!(fits(u128::MAX), [false, false, false, false, true]);
+
+        let labels = selection_report(0).map(|(label, _)| label);
+        assert_eq!(
+            labels,
+            [
+                SmallUnsignedLabel::U8,
+                SmallUnsignedLabel::U16,
+                SmallUnsignedLabel::U32,
+                SmallUnsignedLabel::U64,
+                SmallUnsignedLabel::U128
+            ]
+        );
+
+        // First fit agrees with the label
+        let first_fit = selection_report(MAX_VAL_UNSIGNED as u128)
+            .iter()
+            .find(|(_, fit)| *fit)
+            .map(|(label, _)| *label);
+        assert_eq!(first_fit, Some(SmallUnsignedLabel::new(MAX_VAL_UNSIGNED)));
+    }
+
+    #[test]
+    fn unsigned_wasted_headroom() {
+        assert_eq!(wasted_headroom(256), 65_279);
+        assert_eq!(wasted_headroom(255), 0);
+        assert_eq!(wasted_headroom(0), 255);
+        assert_eq!(wasted_headroom(u16::MAX as u128), 0);
+        assert_eq!(
+            wasted_headroom(u64::MAX as u128 + 1),
+            u128::MAX - (u64::MAX as u128) - 1
+        );
+        assert_eq!(wasted_headroom(u128::MAX), 0);
+
+        const WASTE: u128 = wasted_headroom(MAX_VAL_UNSIGNED as u128);
+        assert_eq!(WASTE, (u16::MAX as u128) - (MAX_VAL_UNSIGNED as u128));
+    }
+
+    #[test]
+    fn unsigned_const_cmp() {
+        const SENTINEL_FITS: bool = small_unsigned_fits(255, 200);
+        const SENTINEL_OVERFLOWS: bool = small_unsigned_fits(256, 200);
+
+        const _: () = assert!(SENTINEL_FITS);
+        const _: () = assert!(!SENTINEL_OVERFLOWS);
+        const _: () = assert!(small_unsigned_fits(u16::MAX as usize, MAX_VAL_UNSIGNED));
+
+        assert_eq!(SmallUnsignedLabel::U8.max_value(), 255);
+        assert_eq!(SmallUnsignedLabel::U16.max_value(), 65_535);
+        assert_eq!(SmallUnsignedLabel::U32.max_value(), u32::MAX as u128);
+        assert_eq!(SmallUnsignedLabel::U64.max_value(), u64::MAX as u128);
+        assert_eq!(SmallUnsignedLabel::U128.max_value(), u128::MAX);
+        assert_eq!(SmallUnsignedLabel::USIZE.max_value(), usize::MAX as u128);
+
+        assert!(small_unsigned_fits(0, 0));
+        assert!(small_unsigned_fits(usize::MAX, usize::MAX));
+        assert!(!small_unsigned_fits(
+            u16::MAX as usize + 1,
+            MAX_VAL_UNSIGNED
+        ));
+    }
+
+    #[test]
+    fn unsigned_const_selected_bytes() {
+        const _: () = assert!(assert_selected_bytes(MAX_VAL_UNSIGNED as u128, 2));
+        const _: () = assert!(!assert_selected_bytes(MAX_VAL_UNSIGNED as u128, 1));
+
+        assert!(assert_selected_bytes(0, 1));
+        assert!(assert_selected_bytes(255, 1));
+        assert!(!assert_selected_bytes(256, 1));
+        assert!(assert_selected_bytes(256, 2));
+        assert!(assert_selected_bytes(u32::MAX as u128, 4));
+        assert!(assert_selected_bytes((u32::MAX as u128) + 1, 8));
+        assert!(assert_selected_bytes(u128::MAX, 16));
+        assert!(!assert_selected_bytes(u128::MAX, size_of::<usize>()));
+
+        // Agrees with the macro
+        assert!(assert_selected_bytes(
+            70_000,
+            size_of::<small_unsigned!(70_000)>()
+        ));
+    }
+
+    #[test]
+    fn unsigned_label_max_distinct_values() {
+        assert_eq!(SmallUnsignedLabel::U8.max_distinct_values(), 256);
+        assert_eq!(SmallUnsignedLabel::U16.max_distinct_values(), 65_536);
+        assert_eq!(SmallUnsignedLabel::U32.max_distinct_values(), 1 << 32);
+        assert_eq!(SmallUnsignedLabel::U64.max_distinct_values(), 1 << 64);
+        assert_eq!(SmallUnsignedLabel::U128.max_distinct_values(), u128::MAX);
+        assert_eq!(
+            SmallUnsignedLabel::USIZE.max_distinct_values(),
+            (usize::MAX as u128) + 1
+        );
+
+        // Round trip with `label_for_distinct_values`
+        let label = SmallUnsignedLabel::U16;
+        assert_eq!(
+            label_for_distinct_values(label.max_distinct_values() as usize),
+            label
+        );
+    }
+
+    #[test]
+    fn unsigned_label_layout_eq() {
+        assert!(SmallUnsignedLabel::U16.layout_eq(SmallUnsignedLabel::U16));
+        assert!(!SmallUnsignedLabel::U16.layout_eq(SmallUnsignedLabel::U32));
+        assert!(SmallUnsignedLabel::USIZE.layout_eq(SmallUnsignedLabel::USIZE));
+        assert_ne!(SmallUnsignedLabel::USIZE, SmallUnsignedLabel::U64);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert!(SmallUnsignedLabel::USIZE.layout_eq(SmallUnsignedLabel::U64));
+            assert!(SmallUnsignedLabel::U64.layout_eq(SmallUnsignedLabel::USIZE));
+            assert!(!SmallUnsignedLabel::USIZE.layout_eq(SmallUnsignedLabel::U32));
+        }
+
+        #[cfg(target_pointer_width = "32")]
+        assert!(SmallUnsignedLabel::USIZE.layout_eq(SmallUnsignedLabel::U32));
+    }
+
+    #[test]
+    fn unsigned_label_fingerprint() {
+        const HEADER: [SmallUnsignedLabel; 3] = [
+            SmallUnsignedLabel::U8,
+            SmallUnsignedLabel::U16,
+            SmallUnsignedLabel::USIZE,
+        ];
+        const STORED: u64 = fingerprint(&HEADER);
+
+        // Identical schema matches, regardless of how the labels were produced
+        let current = [
+            SmallUnsignedLabel::new(200),
+            SmallUnsignedLabel::new(1_000),
+            SmallUnsignedLabel::USIZE,
+        ];
+        assert_eq!(fingerprint(&current), STORED);
+
+        // Reordered, changed, truncated, or extended schemas don't
+        let mut reordered = HEADER;
+        reordered.swap(0, 1);
+        assert_ne!(fingerprint(&reordered), STORED);
+        assert_ne!(
+            fingerprint(&[
+                SmallUnsignedLabel::U8,
+                SmallUnsignedLabel::U32,
+                SmallUnsignedLabel::USIZE
+            ]),
+            STORED
+        );
+        assert_ne!(fingerprint(&HEADER[..2]), STORED);
+        assert_ne!(
+            fingerprint(&[
+                SmallUnsignedLabel::U8,
+                SmallUnsignedLabel::U16,
+                SmallUnsignedLabel::USIZE,
+                SmallUnsignedLabel::U8
+            ]),
+            STORED
+        );
+
+        // `USIZE` isn't resolved to the host width
+        assert_ne!(
+            fingerprint(&[SmallUnsignedLabel::USIZE]),
+            fingerprint(&[SmallUnsignedLabel::new(usize::MAX)])
+        );
+
+        // Pinned, stored fingerprints must never change
+        assert_eq!(fingerprint(&[]), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(
+            fingerprint(&[SmallUnsignedLabel::U8]),
+            0xaf63_bc4c_8601_b62c
+        );
+    }
+
+    #[test]
+    fn unsigned_label_new_native() {
+        assert_eq!(SmallUnsignedLabel::new_native(0), SmallUnsignedLabel::U8);
+        assert_eq!(
+            SmallUnsignedLabel::new_native(usize::MAX),
+            SmallUnsignedLabel::USIZE
+        );
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(
+                SmallUnsignedLabel::new_native(60_000),
+                SmallUnsignedLabel::U16
+            );
+            assert_eq!(
+                SmallUnsignedLabel::new_native(u32::MAX as usize),
+                SmallUnsignedLabel::U32
+            );
+            assert_eq!(
+                SmallUnsignedLabel::new_native((u32::MAX as usize) + 1),
+                SmallUnsignedLabel::USIZE
+            );
+        }
+
+        #[cfg(target_pointer_width = "16")]
+        assert_eq!(
+            SmallUnsignedLabel::new_native(60_000),
+            SmallUnsignedLabel::USIZE
+        );
+
+        // Always layout-compatible with `new`
+        for num in [0, 255, 256, 65_535, 65_536, usize::MAX] {
+            assert!(SmallUnsignedLabel::new_native(num).layout_eq(SmallUnsignedLabel::new(num)));
+        }
+    }
+
+    #[test]
+    fn unsigned_label_byte_size() {
+        const HEADER_BYTES: usize =
+            SmallUnsignedLabel::U8.byte_size() + SmallUnsignedLabel::U32.byte_size();
+        assert_eq!(HEADER_BYTES, 5);
+
+        assert_eq!(SmallUnsignedLabel::U8.byte_size(), size_of::<u8>());
+        assert_eq!(SmallUnsignedLabel::U16.byte_size(), size_of::<u16>());
+        assert_eq!(SmallUnsignedLabel::U32.byte_size(), size_of::<u32>());
+        assert_eq!(SmallUnsignedLabel::U64.byte_size(), size_of::<u64>());
+        assert_eq!(SmallUnsignedLabel::U128.byte_size(), size_of::<u128>());
+        assert_eq!(SmallUnsignedLabel::USIZE.byte_size(), size_of::<usize>());
+    }
+
+    #[test]
+    fn unsigned_label_bit_width() {
+        const REG_BITS: u32 = SmallUnsignedLabel::new(4_095).bit_width();
+        assert_eq!(REG_BITS, 16);
+
+        assert_eq!(SmallUnsignedLabel::U8.bit_width(), 8);
+        assert_eq!(SmallUnsignedLabel::U16.bit_width(), 16);
+        assert_eq!(SmallUnsignedLabel::U32.bit_width(), 32);
+        assert_eq!(SmallUnsignedLabel::U64.bit_width(), 64);
+        assert_eq!(SmallUnsignedLabel::U128.bit_width(), 128);
+        assert_eq!(SmallUnsignedLabel::USIZE.bit_width(), usize::BITS);
+
+        // Round-trip with selection by bit width
+        assert_eq!(
+            size_of::<small_unsigned_bits!(SmallUnsignedLabel::U32.bit_width())>(),
+            SmallUnsignedLabel::U32.byte_size()
+        );
+        for label in [
+            SmallUnsignedLabel::U8,
+            SmallUnsignedLabel::U16,
+            SmallUnsignedLabel::U32,
+            SmallUnsignedLabel::U64,
+        ] {
+            assert_eq!(label.bit_width() as usize, label.byte_size() * 8);
+        }
+    }
+
+    #[test]
+    fn unsigned_label_array_layout() {
+        assert_eq!(
+            SmallUnsignedLabel::new(200).array_layout(10),
+            Layout::array::<u8>(10)
+        );
+        assert_eq!(
+            SmallUnsignedLabel::new(MAX_VAL_UNSIGNED).array_layout(10),
+            Layout::array::<u16>(10)
+        );
+        assert_eq!(
+            SmallUnsignedLabel::U32.array_layout(0),
+            Layout::array::<u32>(0)
+        );
+        assert_eq!(
+            SmallUnsignedLabel::U64.array_layout(3),
+            Layout::array::<u64>(3)
+        );
+        assert_eq!(
+            SmallUnsignedLabel::U128.array_layout(3),
+            Layout::array::<u128>(3)
+        );
+        assert_eq!(
+            SmallUnsignedLabel::USIZE.array_layout(7),
+            Layout::array::<usize>(7)
+        );
+
+        let layout = SmallUnsignedLabel::U16.array_layout(4).unwrap();
+        assert_eq!(layout.size(), 8);
+        assert_eq!(layout.align(), 2);
+
+        assert!(SmallUnsignedLabel::U16.array_layout(usize::MAX).is_err());
+    }
+
+    #[test]
+    fn unsigned_byte_order() {
+        fn round_trip<T: SmallUnsigned + PartialEq + core::fmt::Debug>(val: T, le: &[u8]) {
+            let mut buf = [0_u8; 16];
+            let buf = &mut buf[..T::BYTES];
+            assert_eq!(buf.len(), le.len());
+
+            val.write_le_bytes(buf);
+            assert_eq!(buf, le);
+            assert_eq!(T::read_le_bytes(buf), val);
+
+            val.write_be_bytes(buf);
+            assert!(buf.iter().eq(le.iter().rev()));
+            assert_eq!(T::read_be_bytes(buf), val);
+        }
+
+        round_trip(0xab_u8, &[0xab]);
+        round_trip(0xabcd_u16, &[0xcd, 0xab]);
+        round_trip(0x0102_0304_u32, &[0x04, 0x03, 0x02, 0x01]);
+        round_trip(Wrapping(0x0102_u16), &[0x02, 0x01]);
+        round_trip(
+            NonZeroUsize::new(1).unwrap(),
+            &1_usize.to_le_bytes()[..size_of::<usize>()],
+        );
+
+        #[cfg(target_pointer_width = "64")]
+        round_trip(
+            0x0102_0304_0506_0708_u64,
+            &[0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01],
+        );
+
+        // `u128` only implements `SmallUnsigned` on 128-bit hosts
+        #[cfg(target_pointer_width = "128")]
+        round_trip(u128::MAX, &[0xff; 16]);
+
+        type U16Type = small_unsigned!(1_000);
+        assert_eq!(<U16Type as SmallUnsigned>::BYTES, 2);
+        assert_eq!(<usize as SmallUnsigned>::BYTES, size_of::<usize>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_byte_order_len_mismatch() {
+        1_u16.write_le_bytes(&mut [0_u8; 3]);
+    }
+
+    #[test]
+    fn unsigned_narrow() {
+        type BigIdx = small_unsigned!(100_000);
+        type SmallIdx = small_unsigned!(200);
+
+        let big: BigIdx = 255;
+        assert_eq!(big.narrow::<SmallIdx>(), Some(255));
+        assert_eq!(big.narrow::<u16>(), Some(255));
+
+        let big: BigIdx = 256;
+        assert_eq!(big.narrow::<SmallIdx>(), None);
+        assert_eq!(big.narrow::<u16>(), Some(256));
+        assert_eq!(70_000_u32.narrow::<u16>(), None);
+
+        // Widening always succeeds
+        let small: SmallIdx = 42;
+        assert_eq!(small.narrow::<BigIdx>(), Some(42));
+        assert_eq!(small.narrow::<usize>(), Some(42));
+        assert_eq!(small.narrow::<Wrapping<u8>>(), Some(Wrapping(42)));
+
+        // Invalid for target, not a panic
+        assert_eq!(0_u8.narrow::<NonZeroUsize>(), None);
+        assert_eq!(1_u8.narrow::<NonZeroUsize>(), NonZeroUsize::new(1));
+    }
+
+    #[test]
+    fn unsigned_as_concrete() {
+        type U8Type = small_unsigned!(200);
+        type U16Type = small_unsigned!(1_000);
+
+        let small: U8Type = 200;
+        assert_eq!(small.as_u8(), Some(200));
+        assert_eq!(small.as_u16(), Some(200));
+        assert_eq!(small.as_u32(), Some(200));
+        assert_eq!(small.as_u64(), Some(200));
+        assert_eq!(small.as_u128(), Some(200));
+
+        // Selected type wider than target, regardless of value
+        let medium: U16Type = 5;
+        assert_eq!(medium.as_u8(), None);
+        assert_eq!(medium.as_u16(), Some(5));
+        assert_eq!(u16::MAX.as_u16(), Some(u16::MAX));
+        assert_eq!(u16::MAX.as_u32(), Some(u16::MAX as u32));
+
+        let wide = u32::MAX;
+        assert_eq!(wide.as_u16(), None);
+        assert_eq!(wide.as_u32(), Some(u32::MAX));
+        assert_eq!(wide.as_u64(), Some(u32::MAX as u64));
+
+        assert_eq!(Wrapping(7_u8).as_u8(), Some(7));
+        assert_eq!(Reverse(7_u16).as_u8(), None);
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(1_usize.as_u32(), None);
+            assert_eq!(usize::MAX.as_u64(), Some(u64::MAX));
+            assert_eq!(u64::MAX.as_u128(), Some(u64::MAX as u128));
+        }
+    }
+
+    #[test]
+    fn unsigned_wrapping_sub() {
+        type U8Type = small_unsigned!(200);
+
+        let val: U8Type = 5;
+        assert_eq!(val.wrapping_sub_usize(3), 2);
+        assert_eq!(val.wrapping_sub_usize(6), 255);
+        assert_eq!(val.wrapping_sub_usize(261), 0);
+        assert_eq!(1_000_u16.wrapping_sub_usize(1_001), u16::MAX);
+        assert_eq!(0_usize.wrapping_sub_usize(1), usize::MAX);
+    }
+
+    #[test]
+    fn unsigned_circular_distance() {
+        const RING: usize = 200;
+        type Slot = small_unsigned!(RING);
+
+        let (head, tail): (Slot, Slot) = (10, 50);
+        assert_eq!(head.circular_distance(&tail, RING), 40);
+        assert_eq!(tail.circular_distance(&head, RING), 160);
+        assert_eq!(head.circular_distance(&head, RING), 0);
+
+        // Wrap-around
+        let (head, tail): (Slot, Slot) = (195, 3);
+        assert_eq!(head.circular_distance(&tail, RING), 8);
+        assert_eq!(tail.circular_distance(&head, RING), 192);
+
+        // Out-of-ring values are reduced first
+        let (head, tail): (Slot, Slot) = (199, 255);
+        assert_eq!(head.circular_distance(&tail, RING), 56);
+
+        // Distances sum to the ring size
+        for (a, b) in [(0, 199), (1, 100), (150, 20)] {
+            let (a, b): (Slot, Slot) = (a, b);
+            assert_eq!(
+                a.circular_distance(&b, RING) + b.circular_distance(&a, RING),
+                RING
+            );
+        }
+
+        assert_eq!(usize::MAX.circular_distance(&0, usize::MAX), 0);
+        assert_eq!(1_usize.circular_distance(&0, usize::MAX), usize::MAX - 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_circular_distance_zero_modulus() {
+        1_u8.circular_distance(&2, 0);
+    }
+
+    #[test]
+    fn unsigned_reverse_wrapper() {
+        type U8Type = small_unsigned!(200);
+
+        let vals: [Reverse<U8Type>; 3] = [Reverse(3), Reverse(200), Reverse(0)];
+        assert_eq!(vals.iter().map(SmallUnsigned::usize).sum::<usize>(), 203);
+        assert_eq!(Reverse::<U8Type>::checked_from(42), Reverse(42));
+        assert_eq!(<Reverse<u16> as SmallUnsigned>::MAX, 65_535);
+        assert!(Reverse(255_u8).is_type_max());
+
+        // Ordering is still reversed
+        assert!(Reverse::<U8Type>::checked_from(1) > Reverse::<U8Type>::checked_from(2));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unsigned_reverse_heap() {
+        use alloc::collections::BinaryHeap;
+
+        type U8Type = small_unsigned!(200);
+
+        let mut heap = BinaryHeap::new();
+        for val in [50, 7, 200, 0, 99] {
+            heap.push(Reverse::<U8Type>::checked_from(val));
+        }
+
+        let popped: alloc::vec::Vec<usize> = core::iter::from_fn(|| heap.pop())
+            .map(|val| val.usize())
+            .collect();
+        assert_eq!(popped, [0, 7, 50, 99, 200]);
+    }
+
+    #[test]
+    fn unsigned_reverse_bits() {
+        for val in 0..=u8::MAX {
+            assert_eq!(SmallUnsigned::reverse_bits(&val), val.reverse_bits());
+        }
+
+        assert_eq!(
+            SmallUnsigned::reverse_bits(&0x1234_u16),
+            0x1234_u16.reverse_bits()
+        );
+        assert_eq!(
+            SmallUnsigned::reverse_bits(&1_usize),
+            1_usize.reverse_bits()
+        );
+        assert_eq!(Wrapping(1_u8).reverse_bits(), Wrapping(0x80));
+        assert_eq!(
+            SmallUnsigned::reverse_bits(&NonZeroUsize::new(1).unwrap()).usize(),
+            1_usize.reverse_bits()
+        );
+
+        type U8Type = small_unsigned!(200);
+        let val: U8Type = 0b0000_0110;
+        assert_eq!(SmallUnsigned::reverse_bits(&val), 0b0110_0000);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unsigned_bit_reverse_perm() {
+        use crate::bit_reverse_perm;
+
+        assert_eq!(bit_reverse_perm::<u8>(0), [0]);
+        assert_eq!(bit_reverse_perm::<u8>(1), [0, 1]);
+        assert_eq!(bit_reverse_perm::<u16>(2), [0, 2, 1, 3]);
+
+        // Full width matches the primitive
+        let perm = bit_reverse_perm::<u8>(8);
+        assert_eq!(perm.len(), 256);
+        for (i, rev) in perm.iter().enumerate() {
+            assert_eq!(*rev, (i as u8).reverse_bits());
+        }
+
+        // Involution
+        let perm = bit_reverse_perm::<u16>(10);
+        for (i, rev) in perm.iter().enumerate() {
+            assert_eq!(perm[rev.usize()].usize(), i);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "exceeds T::BITS")]
+    fn unsigned_bit_reverse_perm_too_wide() {
+        crate::bit_reverse_perm::<u8>(9);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "overflows usize")]
+    fn unsigned_bit_reverse_perm_host_width() {
+        crate::bit_reverse_perm::<usize>(usize::BITS);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn unsigned_bit_reverse_perm_nonzero() {
+        crate::bit_reverse_perm::<NonZeroUsize>(1);
+    }
+
+    #[test]
+    fn unsigned_max_const() {
+        fn max_of<T: SmallUnsigned>() -> usize {
+            T::MAX
+        }
+
+        assert_eq!(max_of::<u8>(), 255);
+        assert_eq!(max_of::<u16>(), 65_535);
+        assert_eq!(max_of::<u32>() as u64, u32::MAX as u64);
+        assert_eq!(max_of::<usize>(), usize::MAX);
+        assert_eq!(max_of::<NonZeroUsize>(), usize::MAX);
+        assert_eq!(max_of::<Wrapping<u16>>(), 65_535);
+
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(max_of::<u64>(), usize::MAX);
+
+        // Usable in const context
+        const U8_MAX: usize = <u8 as SmallUnsigned>::MAX;
+        assert_eq!(U8_MAX, 255);
+
+        // Pre-flight check for external data
+        let untrusted: usize = 300;
+        assert!(untrusted > <small_unsigned!(200) as SmallUnsigned>::MAX);
+    }
+
+    #[test]
+    fn unsigned_domain() {
+        type U8Type = small_unsigned!(200);
+
+        let mut full = [0 as U8Type; 256];
+        let mut len = 0;
+        for (slot, val) in full.iter_mut().zip(U8Type::domain(255)) {
+            *slot = val;
+            len += 1;
+        }
+        assert_eq!(len, 256);
+        assert_eq!(U8Type::domain(255).count(), 256);
+        assert!(full.iter().enumerate().all(|(i, val)| val.usize() == i));
+
+        assert_eq!(U8Type::domain(0).count(), 1);
+        assert_eq!(U8Type::domain(200).last(), Some(200));
+        assert_eq!(u16::domain(65_535).count(), 65_536);
+
+        // Type max upper bound ends instead of wrapping
+        let mut top = u8::domain(255).skip(254);
+        assert_eq!(top.next(), Some(254));
+        assert_eq!(top.next(), Some(255));
+        assert_eq!(top.next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_domain_too_large() {
+        let _ = u8::domain(256);
+    }
+
+    #[test]
+    fn unsigned_verify_macro() {
+        const MAX_GEN: usize = 3;
+
+        smallnum_verify! {
+            field Idx: max 500 == 2 bytes;
+            field Gen: max MAX_GEN == 1 byte;
+            field Wide: max (u16::MAX as u128 + 1) == 4 bytes;
+        }
+
+        smallnum_verify! {}
+    }
+
+    #[test]
+    fn unsigned_label_of_value() {
+        fn label_of<T: SmallUnsigned>(val: T) -> SmallUnsignedLabel {
+            val.label()
+        }
+
+        assert_eq!(label_of(0_u16), SmallUnsignedLabel::U16);
+        assert_eq!(label_of::<small_unsigned!(500)>(3), SmallUnsignedLabel::U16);
+        assert_eq!(label_of(u8::MAX), SmallUnsignedLabel::U8);
+        assert_eq!(label_of(7_u32), SmallUnsignedLabel::U32);
+        assert_eq!(label_of(7_usize), SmallUnsignedLabel::USIZE);
+        assert_eq!(label_of(NonZeroUsize::MIN), SmallUnsignedLabel::USIZE);
+        assert_eq!(label_of(Wrapping(7_usize)), SmallUnsignedLabel::USIZE);
+        assert_eq!(label_of(Reverse(7_u16)), SmallUnsignedLabel::U16);
+
+        #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
+        assert_eq!(label_of(7_u64), SmallUnsignedLabel::U64);
+    }
+
+    #[test]
+    fn unsigned_bit_width() {
+        // Generic, so inherent methods on the primitives can't shadow it
+        fn width<T: SmallUnsigned>(val: T) -> u32 {
+            val.bit_width()
+        }
+
+        assert_eq!(width(0_u8), 8);
+        assert_eq!(width(u8::MAX), 8);
+        assert_eq!(width(1_u16), 16);
+        assert_eq!(width(1_u32), 32);
+        assert_eq!(width(1_usize), usize::BITS);
+        assert_eq!(width(NonZeroUsize::MIN), usize::BITS);
+        assert_eq!(width(Wrapping(1_u16)), 16);
+        assert_eq!(width::<small_unsigned!(200)>(3), 8);
+
+        #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
+        assert_eq!(width(1_u64), 64);
+
+        #[cfg(target_pointer_width = "128")]
+        assert_eq!(width(1_u128), 128);
+    }
+
+    #[test]
+    fn unsigned_flat_index() {
+        const ROWS: usize = 10;
+        const COLS: usize = 20;
+        type Idx = small_unsigned!(ROWS * COLS);
+
+        assert_eq!(Idx::flat_index(0, 0, COLS, ROWS * COLS), Some(0));
+        assert_eq!(Idx::flat_index(3, 7, COLS, ROWS * COLS), Some(67));
+        assert_eq!(Idx::flat_index(9, 19, COLS, ROWS * COLS), Some(199));
+
+        // Out of bounds
+        assert_eq!(Idx::flat_index(10, 0, COLS, ROWS * COLS), None);
+        assert_eq!(Idx::flat_index(0, 20, COLS, ROWS * COLS), None);
+        assert_eq!(Idx::flat_index(1, 0, 0, 0), None);
+
+        // Overflow
+        assert_eq!(usize::flat_index(usize::MAX, 0, 2, usize::MAX), None);
+        assert_eq!(
+            usize::flat_index(1, usize::MAX - 1, usize::MAX, usize::MAX),
+            None
+        );
+
+        // In bounds, but doesn't fit the primitive
+        assert_eq!(u8::flat_index(16, 0, 16, 1_000), None);
+        assert_eq!(u8::flat_index(15, 15, 16, 1_000), Some(255));
+    }
+
+    #[test]
+    fn unsigned_saturating_from() {
+        type U8Type = small_unsigned!(200);
+
+        assert_eq!(u8::saturating_from(300), 255);
+        assert_eq!(U8Type::saturating_from(255), 255);
+        assert_eq!(U8Type::saturating_from(42), 42);
+        assert_eq!(u16::saturating_from(usize::MAX), u16::MAX);
+        assert_eq!(usize::saturating_from(usize::MAX), usize::MAX);
+        assert_eq!(Wrapping::<u8>::saturating_from(1_000), Wrapping(255));
+    }
+
+    #[test]
+    fn unsigned_overflow_amount() {
+        type U8Type = small_unsigned!(200);
+
+        assert_eq!(U8Type::overflow_amount(0), None);
+        assert_eq!(U8Type::overflow_amount(255), None);
+        assert_eq!(U8Type::overflow_amount(256), Some(1));
+        assert_eq!(U8Type::overflow_amount(300), Some(45));
+
+        assert_eq!(u16::overflow_amount(65_535), None);
+        assert_eq!(u16::overflow_amount(65_536), Some(1));
+        assert_eq!(u16::overflow_amount(usize::MAX), Some(usize::MAX - 65_535));
+
+        #[cfg(any(target_pointer_width = "64", target_pointer_width = "128"))]
+        {
+            assert_eq!(u32::overflow_amount(u32::MAX as usize), None);
+            assert_eq!(u32::overflow_amount((u32::MAX as usize) + 10), Some(10));
+        }
+
+        // Nothing exceeds host width
+        assert_eq!(usize::overflow_amount(usize::MAX), None);
+        assert_eq!(<Wrapping<u8>>::overflow_amount(257), Some(2));
+    }
+
+    #[test]
+    fn unsigned_try_from() {
+        type U8Type = small_unsigned!(200);
+
+        assert_eq!(U8Type::try_from(255), Ok(255));
+        assert_eq!(
+            U8Type::try_from(256),
+            Err(SmallNumError::Overflow {
+                value: 256,
+                max: u8::MAX as usize,
+                label: SmallUnsignedLabel::U8
+            })
+        );
+        assert_eq!(
+            u16::try_from(70_000),
+            Err(SmallNumError::Overflow {
+                value: 70_000,
+                max: u16::MAX as usize,
+                label: SmallUnsignedLabel::U16
+            })
+        );
+        assert_eq!(usize::try_from(usize::MAX), Ok(usize::MAX));
+
+        assert_eq!(NonZeroUsize::try_from(0), Err(SmallNumError::Zero));
+        assert_eq!(NonZeroUsize::try_from(3), Ok(NonZeroUsize::new(3).unwrap()));
+
+        assert_eq!(
+            Wrapping::<u8>::try_from(300),
+            Err(SmallNumError::Overflow {
+                value: 300,
+                max: u8::MAX as usize,
+                label: SmallUnsignedLabel::U8
+            })
+        );
+    }
+
+    #[test]
+    fn unsigned_truncating_from() {
+        type U8Type = small_unsigned!(200);
+        type U16Type = small_unsigned!(MAX_VAL_UNSIGNED);
+
+        for num in [0, 1, 100, 200, 255] {
+            assert_eq!(U8Type::truncating_from(num), U8Type::checked_from(num));
+        }
+        assert_eq!(U16Type::truncating_from(512), U16Type::checked_from(512));
+        assert_eq!(usize::truncating_from(usize::MAX), usize::MAX);
+
+        // Out-of-range truncates like `as`
+        assert_eq!(U8Type::truncating_from(256), 0);
+        assert_eq!(U8Type::truncating_from(300), 300_usize as u8);
+        assert_eq!(U16Type::truncating_from(70_000), 70_000_usize as u16);
+        assert_eq!(U8Type::truncating_from(usize::MAX), u8::MAX);
+
+        // Original name still works
+        #[allow(deprecated)]
+        let num = <U8Type as SmallUnsigned>::new_unchecked(300);
+        assert_eq!(num, U8Type::truncating_from(300));
+    }
+
+    #[test]
+    fn unsigned_from_u64() {
+        type U16Type = small_unsigned!(500);
+
+        assert_eq!(U16Type::checked_from_u64(500_u64), 500_u16);
+        assert_eq!(U16Type::checked_from_u64(u16::MAX as u64), u16::MAX);
+        assert_eq!(usize::checked_from_u64(u32::MAX as u64), u32::MAX as usize);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_from_u64_overflow() {
+        type U16Type = small_unsigned!(500);
+
+        U16Type::checked_from_u64(u16::MAX as u64 + 1);
+    }
+
+    #[test]
+    fn unsigned_from_u64_width() {
+        // 32-bit host: `usize` is `u32`-wide
+        assert!(super::u64_fits_width(u32::MAX as u64, 32));
+        assert!(!super::u64_fits_width(u32::MAX as u64 + 1, 32));
+        assert!(!super::u64_fits_width(u64::MAX, 32));
+
+        // 64-bit host: every `u64` fits
+        assert!(super::u64_fits_width(u64::MAX, 64));
+
+        // 16-bit host
+        assert!(super::u64_fits_width(u16::MAX as u64, 16));
+        assert!(!super::u64_fits_width(u16::MAX as u64 + 1, 16));
+    }
+
+    // Fits the host's `usize`, but not the target: rejected by the narrowing assert, not truncated.
+    // The narrower-host path is covered by `unsigned_from_u64_width`.
+    #[test]
+    #[should_panic]
+    fn unsigned_from_u64_narrow_overflow() {
+        type U32Type = small_unsigned!(100_000);
+
+        U32Type::checked_from_u64(u32::MAX as u64 + 1);
+    }
+
+    #[test]
+    fn unsigned_from_magnitude() {
+        type U16Type = small_unsigned!(500);
+
+        assert_eq!(U16Type::from_magnitude(300, true), Some((300, true)));
+        assert_eq!(U16Type::from_magnitude(300, false), Some((300, false)));
+        assert_eq!(
+            U16Type::from_magnitude(u16::MAX as u128, true),
+            Some((u16::MAX, true))
+        );
+        assert_eq!(U16Type::from_magnitude(u16::MAX as u128 + 1, false), None);
+
+        // Negative zero keeps its sign
+        assert_eq!(U16Type::from_magnitude(0, true), Some((0, true)));
+        assert_eq!(U16Type::from_magnitude(0, false), Some((0, false)));
+
+        // Above `i128::MAX`, no signed value could carry it
+        assert_eq!(U16Type::from_magnitude((i128::MAX as u128) + 1, true), None);
+        assert_eq!(usize::from_magnitude(u128::MAX, true), None);
+        assert_eq!(
+            usize::from_magnitude(usize::MAX as u128, true),
+            Some((usize::MAX, true))
+        );
+    }
+
+    #[test]
+    fn unsigned_from_signed_magnitude() {
+        type U16Type = small_unsigned!(500);
+
+        assert_eq!(
+            U16Type::try_from_i128_signed_magnitude(-300),
+            Some((300, true))
+        );
+        assert_eq!(
+            U16Type::try_from_i128_signed_magnitude(300),
+            Some((300, false))
+        );
+        assert_eq!(U16Type::try_from_i128_signed_magnitude(0), Some((0, false)));
+        assert_eq!(
+            U16Type::try_from_i128_signed_magnitude(-(u16::MAX as i128)),
+            Some((u16::MAX, true))
+        );
+
+        assert_eq!(
+            U16Type::try_from_i128_signed_magnitude(u16::MAX as i128 + 1),
+            None
+        );
+        assert_eq!(
+            U16Type::try_from_i128_signed_magnitude(-(u16::MAX as i128) - 1),
+            None
+        );
+        assert_eq!(usize::try_from_i128_signed_magnitude(i128::MIN), None);
+        assert_eq!(
+            usize::try_from_i128_signed_magnitude(-(usize::MAX as i128)),
+            Some((usize::MAX, true))
+        );
+    }
+
+    #[test]
+    fn unsigned_predicates() {
+        type U8Type = small_unsigned!(200);
+
+        let zero: U8Type = 0;
+        let max: U8Type = 255;
+        let mid: U8Type = 128;
+
+        assert!(zero.is_zero());
+        assert!(!zero.is_type_max());
+
+        assert!(!max.is_zero());
+        assert!(max.is_type_max());
+
+        assert!(!mid.is_zero());
+        assert!(!mid.is_type_max());
+
+        assert!(usize::MAX.is_type_max());
+        assert!(!(u8::MAX as usize).is_type_max());
+    }
+
+    #[test]
+    fn unsigned_narrow_into() {
+        type U8Type = small_unsigned!(200);
+
+        let src: [usize; 4] = [0, 1, 200, 255];
+        let mut dst: [U8Type; 4] = [0; 4];
+
+        assert_eq!(narrow_into(&src, &mut dst), Ok(()));
+        assert_eq!(dst, [0, 1, 200, 255]);
+
+        let mut short: [U8Type; 3] = [0; 3];
+        assert_eq!(
+            narrow_into(&src, &mut short),
+            Err(SmallNumError::LengthMismatch {
+                src_len: 4,
+                dst_len: 3
+            })
+        );
+
+        let overflow: [usize; 4] = [7, 256, 7, 1_000];
+        let mut untouched: [U8Type; 4] = [0; 4];
+        assert_eq!(
+            narrow_into(&overflow, &mut untouched),
+            Err(SmallNumError::Overflow {
+                value: 256,
+                max: 255,
+                label: SmallUnsignedLabel::U8
+            })
+        );
+        assert_eq!(untouched, [0; 4]);
+    }
+
+    #[test]
+    fn unsigned_for_each_index() {
+        let mut visited = [usize::MAX; 8];
+        let mut cnt = 0;
+
+        for_each_index(5, |i| {
+            visited[cnt] = i;
+            cnt += 1;
+        });
+
+        assert_eq!(cnt, 6);
+        assert_eq!(visited[..cnt], [0, 1, 2, 3, 4, 5]);
+
+        let mut cnt = 0;
+        for_each_index(0, |i| {
+            assert_eq!(i, 0);
+            cnt += 1;
+        });
+        assert_eq!(cnt, 1);
+    }
+
+    #[test]
+    fn unsigned_euclid() {
+        type U8Type = small_unsigned!(200);
+
+        let num: U8Type = 255;
+
+        assert_eq!(num.rem_euclid_usize(10), 5);
+        assert_eq!(num.div_euclid_usize(10), 25);
+        assert_eq!(num.rem_euclid_usize(1_000), 255);
+        assert_eq!(num.div_euclid_usize(1_000), 0);
+        assert_eq!(num.rem_euclid_usize(usize::MAX), 255);
+        assert_eq!(num.div_euclid_usize(1), 255);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_euclid_div_zero() {
+        type U8Type = small_unsigned!(200);
+
+        let num: U8Type = 200;
+        num.div_euclid_usize(0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_euclid_rem_zero() {
+        type U8Type = small_unsigned!(200);
+
+        let num: U8Type = 200;
+        num.rem_euclid_usize(0);
+    }
+
+    #[test]
+    fn unsigned_euclid_nonzero() {
+        let num = NonZeroUsize::new(7).unwrap();
+
+        assert_eq!(num.rem_euclid_usize(4).get(), 3);
+        assert_eq!(num.div_euclid_usize(4).get(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_euclid_nonzero_rem_zero_result() {
+        let num = NonZeroUsize::new(8).unwrap();
+        num.rem_euclid_usize(4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_euclid_nonzero_div_zero_result() {
+        let num = NonZeroUsize::new(3).unwrap();
+        num.div_euclid_usize(4);
+    }
+
+    #[test]
+    fn unsigned_complement() {
+        type U8Type = small_unsigned!(200);
+
+        let zero: U8Type = 0;
+        let one: U8Type = 1;
+        let mid: U8Type = 100;
+        let last: U8Type = 199;
+        let full: U8Type = 200;
+
+        assert_eq!(zero.complement(200), 0);
+        assert_eq!(one.complement(200), 199);
+        assert_eq!(mid.complement(200), 100);
+        assert_eq!(last.complement(200), 1);
+        assert_eq!(full.complement(200), 0);
+
+        // Involution for in-range values
+        assert_eq!(last.complement(200).complement(200), last);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_complement_exceeds_modulus() {
+        let num: small_unsigned!(200) = 201;
+        let _ = num.complement(200);
+    }
+
+    #[test]
+    fn unsigned_from_digit_char() {
+        type U8Type = small_unsigned!(200);
+
+        assert_eq!(U8Type::from_digit_char('f', 16), Some(15));
+        assert_eq!(U8Type::from_digit_char('F', 16), Some(15));
+        assert_eq!(U8Type::from_digit_char('7', 8), Some(7));
+        assert_eq!(U8Type::from_digit_char('z', 36), Some(35));
+        assert_eq!(usize::from_digit_char('1', 2), Some(1));
+
+        assert_eq!(U8Type::from_digit_char('g', 16), None);
+        assert_eq!(U8Type::from_digit_char('8', 8), None);
+        assert_eq!(U8Type::from_digit_char(' ', 10), None);
+        assert_eq!(U8Type::from_digit_char('٣', 10), None);
+
+        // Valid digit, invalid for the type
+        assert_eq!(
+            <NonZeroUsize as SmallUnsigned>::from_digit_char('0', 10),
+            None
+        );
+        assert_eq!(
+            <NonZeroUsize as SmallUnsigned>::from_digit_char('7', 10),
+            NonZeroUsize::new(7)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radix")]
+    fn unsigned_from_digit_char_bad_radix() {
+        let _ = u8::from_digit_char('0', 1);
+    }
+
+    #[test]
+    fn unsigned_from_digit_str() {
+        type U8Type = small_unsigned!(200);
+        type U16Type = small_unsigned!(500);
+
+        assert_eq!(U8Type::from_digit_str("ff", 16), Some(255));
+        assert_eq!(U8Type::from_digit_str("Ff", 16), Some(255));
+        assert_eq!(U8Type::from_digit_str("0", 10), Some(0));
+        assert_eq!(U8Type::from_digit_str("0042", 10), Some(42));
+        assert_eq!(U8Type::from_digit_str("1010", 2), Some(10));
+        assert_eq!(U16Type::from_digit_str("1f4", 16), Some(500));
+        assert_eq!(U16Type::from_digit_str("zz", 36), Some(1_295));
+
+        // Exceeds the primitive
+        assert_eq!(U8Type::from_digit_str("100", 16), None);
+        assert_eq!(U16Type::from_digit_str("65536", 10), None);
+        assert_eq!(usize::from_digit_str("1ffffffffffffffffffff", 16), None);
+
+        // Invalid digits
+        assert_eq!(U8Type::from_digit_str("", 10), None);
+        assert_eq!(U8Type::from_digit_str("12a", 10), None);
+        assert_eq!(U8Type::from_digit_str("-1", 10), None);
+        assert_eq!(U8Type::from_digit_str("1 2", 10), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix")]
+    fn unsigned_from_digit_str_bad_radix() {
+        let _ = u8::from_digit_str("", 37);
+    }
+
+    #[test]
+    fn unsigned_splat() {
+        type U16Type = small_unsigned!(500);
+
+        let arr: [U16Type; 8] = U16Type::splat(500);
+        assert!(arr.iter().all(|num| num.usize() == 500));
+
+        let arr = usize::splat::<3>(7);
+        assert_eq!(arr, [7, 7, 7]);
+
+        let empty: [U16Type; 0] = U16Type::splat(1);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_splat_overflow() {
+        type U8Type = small_unsigned!(200);
+
+        let _: [U8Type; 4] = U8Type::splat(256);
+    }
+
+    #[test]
+    fn unsigned_widen_slice_into() {
+        let src: [u16; 4] = [0, 1, 256, u16::MAX];
+        let mut dst = [usize::MAX; 4];
+
+        u16::widen_slice_into(&src, &mut dst);
+        assert_eq!(dst, [0, 1, 256, u16::MAX as usize]);
+
+        let mut empty: [usize; 0] = [];
+        u8::widen_slice_into(&[], &mut empty);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_widen_slice_into_len_mismatch() {
+        let mut dst = [0_usize; 2];
+        u8::widen_slice_into(&[1, 2, 3], &mut dst);
+    }
+
+    #[test]
+    fn unsigned_nonzero_usize() {
+        let idx = NonZeroUsize::checked_from(42);
+        assert_eq!(idx.usize(), 42);
+        assert_eq!(idx, NonZeroUsize::new(42).unwrap());
+
+        assert!(!idx.is_zero());
+        assert!(NonZeroUsize::checked_from(usize::MAX).is_type_max());
+        assert_eq!(NonZeroUsize::from_bits(idx.to_bits()), idx);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_nonzero_usize_rejects_zero() {
+        let _ = NonZeroUsize::checked_from(0);
+    }
+
+    #[test]
+    fn unsigned_wrapping() {
+        type U8Type = small_unsigned!(200);
+
+        let vals: [Wrapping<U8Type>; 3] = [Wrapping(1), Wrapping(200), Wrapping(255)];
+        let mut widened = vals.iter().map(SmallUnsigned::usize);
+        assert_eq!(widened.next(), Some(1));
+        assert_eq!(widened.next(), Some(200));
+        assert_eq!(widened.next(), Some(255));
+        assert_eq!(widened.next(), None);
+
+        let refs: [&Wrapping<U8Type>; 2] = [&vals[0], &vals[2]];
+        assert_eq!(refs.iter().map(|v| v.usize()).sum::<usize>(), 256);
+
+        // Wrapping arithmetic is preserved, widening sees the wrapped result
+        let sum = vals[2] + Wrapping(2);
+        assert_eq!(sum.usize(), 1);
+
+        assert_eq!(Wrapping::<U8Type>::checked_from(7), Wrapping(7));
+        assert_eq!(<Wrapping<U8Type> as SmallUnsigned>::BITS, 8);
+        assert!(vals[2].is_type_max());
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_wrapping_checked_from_overflow() {
+        let _ = Wrapping::<u8>::checked_from(256);
+    }
+
+    #[test]
+    fn unsigned_array_widen_all() {
+        type U8Type = small_unsigned!(200);
+
+        let idx: [U8Type; 3] = [1, 200, 255];
+        assert_eq!(idx.widen_all(), [1_usize, 200, 255]);
+
+        let empty: [u16; 0] = [];
+        assert_eq!(empty.widen_all(), [0_usize; 0]);
+
+        // Row-major flattening, e.g. for a 3D grid
+        const DIMS: [usize; 3] = [4, 5, 6];
+        let [x, y, z] = [3_u8, 2, 1].widen_all();
+        assert_eq!((x * DIMS[1] * DIMS[2]) + (y * DIMS[2]) + z, 103);
+    }
+
+    #[test]
+    fn unsigned_bit_range() {
+        type U16Type = small_unsigned!(MAX_VAL_UNSIGNED * 100);
+
+        let val: U16Type = 0b1010_1100_1111_0001;
+        assert_eq!(val.bit_range(4, 8), 0b1111);
+        assert_eq!(val.bit_range(6, 12), 0b11_0011);
+        assert_eq!(val.bit_range(0, 1), 1);
+        assert_eq!(val.bit_range(12, 16), 0b1010);
+        assert_eq!(val.bit_range(0, 16), val.usize());
+        assert_eq!(val.bit_range(16, 16), 0);
+        assert_eq!(val.bit_range(5, 5), 0);
+
+        assert_eq!(usize::MAX.bit_range(0, usize::BITS), usize::MAX);
+        assert_eq!(usize::MAX.bit_range(1, usize::BITS), usize::MAX >> 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_bit_range_past_bits() {
+        let val: u16 = 1;
+        let _ = val.bit_range(8, 17);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_bit_range_inverted() {
+        let val: u16 = 1;
+        let _ = val.bit_range(8, 4);
+    }
+
+    #[test]
+    fn unsigned_narrow_checked() {
+        type U8Type = small_unsigned!(200);
+
+        let src: [usize; 4] = [0, 7, 200, 255];
+        let mut dst: [U8Type; 4] = [0; 4];
+        assert_eq!(U8Type::narrow_checked(&src, &mut dst), Ok(()));
+        assert_eq!(dst, [0, 7, 200, 255]);
+
+        let overflow: [usize; 5] = [1, 2, 256, 3, 1_000];
+        let mut untouched: [U8Type; 5] = [0; 5];
+        assert_eq!(U8Type::narrow_checked(&overflow, &mut untouched), Err(2));
+        assert_eq!(untouched, [0; 5]);
+
+        let mut wide = [0_usize; 5];
+        assert_eq!(usize::narrow_checked(&overflow, &mut wide), Ok(()));
+        assert_eq!(wide, overflow);
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsigned_narrow_checked_len_mismatch() {
+        let mut dst = [0_u8; 2];
+        let _ = u8::narrow_checked(&[1, 2, 3], &mut dst);
+    }
+
+    #[test]
+    fn unsigned_bits() {
+        type U8Type = small_unsigned!(200);
+        type U16Type = small_unsigned!(500);
+
+        let u8_num: U8Type = 0b1010_0110;
+        let u8_bits = u8_num.to_bits();
+
+        assert_eq!(u8_bits.len(), 8);
+        assert_eq!(
+            u8_bits,
+            [false, true, true, false, false, true, false, true]
+        );
+        assert_eq!(U8Type::from_bits(u8_bits), u8_num);
+
+        assert_eq!(U8Type::from_bits([false; 8]), 0);
+        assert_eq!(U8Type::from_bits([true; 8]), u8::MAX);
+
+        let u16_num: U16Type = 500;
+        let u16_bits = u16_num.to_bits();
+
+        assert_eq!(u16_bits.len(), 16);
+        assert_eq!(U16Type::from_bits(u16_bits), u16_num);
+
+        let usize_num: usize = usize::MAX - 1;
+        let usize_bits = usize_num.to_bits();
+
+        assert_eq!(usize_bits.len(), usize::BITS as usize);
+        assert!(!usize_bits[0]);
+        assert!(usize_bits[1..].iter().all(|b| *b));
+        assert_eq!(usize::from_bits(usize_bits), usize_num);
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn unsigned_label_defmt() {
+        // Encoding needs a global logger (i.e. an embedded target), so only check the impl exists on host
+        static_assertions::assert_impl_all!(SmallUnsignedLabel: defmt::Format);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn unsigned_label_serde() {
+        let json = serde_json::to_string(&SmallUnsignedLabel::U16).unwrap();
+        assert_eq!(json, "\"U16\"");
+        assert_eq!(
+            serde_json::from_str::<SmallUnsignedLabel>(&json).unwrap(),
+            SmallUnsignedLabel::U16
+        );
+
+        let json = serde_json::to_string(&SmallUnsignedLabel::USIZE).unwrap();
+        assert_eq!(json, "\"USIZE\"");
+        assert_eq!(
+            serde_json::from_str::<SmallUnsignedLabel>(&json).unwrap(),
+            SmallUnsignedLabel::USIZE
+        );
+
+        // Unknown variant is an error, not a panic
+        assert!(serde_json::from_str::<SmallUnsignedLabel>("\"U7\"").is_err());
+        assert!(serde_json::from_str::<SmallUnsignedLabel>("16").is_err());
+    }
 }
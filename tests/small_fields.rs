@@ -0,0 +1,77 @@
+#![cfg(feature = "derive")]
+
+use core::mem::size_of;
+use smallnum::{small_fields, small_unsigned};
+
+const MAX_CAPACITY: usize = 50_000;
+const MAX_DEPTH: usize = 64;
+
+#[small_fields]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct NodeMeta {
+    #[small(max = "MAX_CAPACITY")]
+    pub subtree_size: usize,
+
+    #[small(max = MAX_DEPTH)]
+    depth: usize,
+
+    pub flags: u8,
+}
+
+#[small_fields]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+
+    #[small(max = "MAX_CAPACITY")]
+    len: usize,
+}
+
+#[small_fields]
+struct Generic<T: Copy> {
+    value: T,
+
+    #[small(max = "MAX_CAPACITY * 2")]
+    count: usize,
+}
+
+#[test]
+fn small_fields_types() {
+    let meta = NodeMeta::default();
+    let _: small_unsigned!(MAX_CAPACITY) = meta.subtree_size;
+    let _: small_unsigned!(MAX_DEPTH) = meta.depth;
+
+    assert_eq!(size_of::<NodeMeta>(), 4);
+    assert_eq!(size_of::<Packed>(), 3);
+}
+
+#[test]
+fn small_fields_accessors() {
+    let mut meta = NodeMeta::default();
+    meta.set_subtree_size(MAX_CAPACITY);
+    meta.set_depth(7);
+
+    assert_eq!(meta.subtree_size(), MAX_CAPACITY);
+    assert_eq!(meta.depth(), 7);
+    assert_eq!(meta.subtree_size, 50_000_u16);
+
+    let mut packed = Packed { tag: 1, len: 0 };
+    packed.set_len(1_000);
+    assert_eq!(packed.len(), 1_000);
+    assert_eq!(packed.tag, 1);
+
+    let mut generic = Generic {
+        value: 'a',
+        count: 0,
+    };
+    generic.set_count(MAX_CAPACITY * 2);
+    assert_eq!(generic.count(), MAX_CAPACITY * 2);
+    assert_eq!(generic.value, 'a');
+}
+
+#[test]
+#[should_panic]
+fn small_fields_setter_overflow() {
+    let mut meta = NodeMeta::default();
+    meta.set_depth(256);
+}
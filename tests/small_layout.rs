@@ -0,0 +1,60 @@
+#![cfg(feature = "derive")]
+
+use smallnum::{small_unsigned, FieldLayout, SmallLayout, SmallUnsignedLabel};
+
+const MAX_NODES: usize = 1_000;
+const MAX_WEIGHT: usize = 100_000;
+
+#[derive(SmallLayout)]
+#[allow(dead_code)]
+struct Edge {
+    src: small_unsigned!(MAX_NODES),
+    dst: small_unsigned!(MAX_NODES),
+    weight: small_unsigned!(MAX_WEIGHT),
+    kind: u8,
+}
+
+#[derive(SmallLayout)]
+#[allow(dead_code)]
+struct Tuple(u8, u16);
+
+#[derive(SmallLayout)]
+struct Empty {}
+
+#[test]
+fn small_layout_consts() {
+    assert_eq!(
+        Edge::FIELD_LABELS,
+        &[
+            SmallUnsignedLabel::U16,
+            SmallUnsignedLabel::U16,
+            SmallUnsignedLabel::U32,
+            SmallUnsignedLabel::U8,
+        ]
+    );
+    assert_eq!(Edge::PACKED_SIZE, 2 + 2 + 4 + 1);
+
+    // Padding is not counted
+    assert!(Edge::PACKED_SIZE < core::mem::size_of::<Edge>());
+
+    assert_eq!(
+        Tuple::FIELD_LABELS,
+        &[SmallUnsignedLabel::U8, SmallUnsignedLabel::U16]
+    );
+    assert_eq!(Tuple::PACKED_SIZE, 3);
+
+    assert!(Empty::FIELD_LABELS.is_empty());
+    assert_eq!(Empty::PACKED_SIZE, 0);
+}
+
+#[test]
+fn small_layout_agrees_with_field_layout() {
+    const LABELS: [SmallUnsignedLabel; 4] = [
+        Edge::FIELD_LABELS[0],
+        Edge::FIELD_LABELS[1],
+        Edge::FIELD_LABELS[2],
+        Edge::FIELD_LABELS[3],
+    ];
+
+    assert_eq!(FieldLayout::packed(LABELS).size(), Edge::PACKED_SIZE);
+}
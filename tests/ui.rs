@@ -0,0 +1,12 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[cfg(feature = "derive")]
+#[test]
+fn ui_derive() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui_derive/*.rs");
+}
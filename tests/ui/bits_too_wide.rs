@@ -0,0 +1,5 @@
+use smallnum::small_unsigned_bits;
+
+fn main() {
+    let _reg: small_unsigned_bits!(129) = 5;
+}
@@ -0,0 +1,5 @@
+use smallnum::small_unsigned_headroom;
+
+fn main() {
+    let _idx: small_unsigned_headroom!(u128::MAX, 200) = 5;
+}
@@ -0,0 +1,5 @@
+use smallnum::small_int;
+
+fn main() {
+    let _idx: small_int!(u128::MAX) = 5;
+}
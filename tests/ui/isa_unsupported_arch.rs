@@ -0,0 +1,5 @@
+use smallnum::small_unsigned_isa;
+
+fn main() {
+    let _idx: small_unsigned_isa!(100, arch = "mips") = 5;
+}
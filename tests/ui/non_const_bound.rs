@@ -0,0 +1,6 @@
+use smallnum::small_unsigned;
+
+fn main() {
+    let max = 500;
+    let _idx: small_unsigned!(max) = 5;
+}
@@ -0,0 +1,5 @@
+use smallnum::small_unsigned_pow2;
+
+fn main() {
+    let _idx: small_unsigned_pow2!(128) = 5;
+}
@@ -0,0 +1,5 @@
+use smallnum::small_unsigned_sum;
+
+fn main() {
+    let _idx: small_unsigned_sum!(u128::MAX, 1) = 5;
+}
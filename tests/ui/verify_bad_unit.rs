@@ -0,0 +1,7 @@
+use smallnum::smallnum_verify;
+
+smallnum_verify! {
+    field Idx: max 500 == 16 bits;
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use smallnum::smallnum_verify;
+
+smallnum_verify! {
+    field Idx: max 500 == 2 bytes;
+    field Gen: max 300 == 1 byte;
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use smallnum::small_fields;
+
+#[small_fields]
+struct Node {
+    #[small(min = "10")]
+    len: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use smallnum::small_fields;
+
+#[small_fields]
+struct Node {
+    #[small(max = "std::env::args().count()")]
+    len: usize,
+}
+
+fn main() {}
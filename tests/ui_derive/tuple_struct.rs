@@ -0,0 +1,6 @@
+use smallnum::small_fields;
+
+#[small_fields]
+struct Node(usize);
+
+fn main() {}